@@ -0,0 +1,27 @@
+//! Compares [Euui::random_fast] with a [XorShiftRng] against [Euui::random].
+//!
+//! Run with `cargo bench --bench random_fast`.
+
+use euui::{Euui, XorShiftRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 1_000_000;
+
+/// Returns the mean duration of `f` over [ITERATIONS] calls.
+fn measure<F: FnMut() -> Euui>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let mut rng = XorShiftRng::new(42);
+    let fast = measure(|| Euui::random_fast(&mut rng));
+    let random = measure(Euui::random);
+    println!("Euui::random_fast (XorShiftRng) : {:?} per EUUI", fast);
+    println!("Euui::random                    : {:?} per EUUI", random);
+    println!("speedup                         : {:.1}x", random.as_secs_f64() / fast.as_secs_f64());
+}
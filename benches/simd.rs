@@ -0,0 +1,29 @@
+//! Compares the `simd` path of [Euui::eq_batch] against the scalar comparison.
+//!
+//! Run with `cargo +nightly bench --bench simd --features simd`.
+
+use euui::Euui;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LENGTH: usize = 10_000;
+const ROUNDS: u32 = 200;
+
+/// Returns the mean duration of `f` per EUUI, over [ROUNDS] calls processing [LENGTH] EUUIs each.
+fn measure<T, F: FnMut() -> T>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    start.elapsed() / (ROUNDS * LENGTH as u32)
+}
+
+fn main() {
+    let a: Vec<Euui> = (0..LENGTH).map(|_| Euui::random()).collect();
+    let mut b = a.clone();
+    b.iter_mut().step_by(2).for_each(|euui| *euui = Euui::random());
+
+    let simd_eq = measure(|| Euui::eq_batch(black_box(&a), black_box(&b)));
+    let scalar_eq = measure(|| black_box(&a).iter().zip(black_box(&b)).map(|(a, b)| a == b).collect::<Vec<bool>>());
+    println!("eq  : simd {:?}, scalar {:?} per EUUI", simd_eq, scalar_eq);
+}
@@ -0,0 +1,550 @@
+use crate::Euui;
+use std::cmp::Ordering;
+
+impl Euui {
+    /// Returns the bit `index` of this EUUI, `0` being the most significant bit and `511` the least significant one.
+    fn msb_bit(&self, index: usize) -> bool {
+        self.0[index / 128] >> (127 - index % 128) & 1 == 1
+    }
+
+    /// Returns the number of leading zero bits of this EUUI (512 for the zero EUUI).
+    fn leading_zeros(&self) -> usize {
+        self.0
+            .iter()
+            .position(|guid| *guid != 0)
+            .map_or(512, |index| index * 128 + self.0[index].leading_zeros() as usize)
+    }
+
+    /// Shifts this EUUI left by one bit, filling the least significant bit with `low`.
+    ///
+    /// Returns the shifted value and the bit shifted out.
+    fn shl1(&self, low: bool) -> (Self, bool) {
+        let mut guids = self.0;
+        let mut carry = low as u128;
+        for guid in guids.iter_mut().rev() {
+            let out = *guid >> 127;
+            *guid = *guid << 1 | carry;
+            carry = out;
+        }
+        (Self(guids), carry == 1)
+    }
+
+    /// Returns the number of trailing zero bits of this EUUI (512 for zero).
+    fn trailing_zeros(&self) -> u32 {
+        let mut count = 0;
+        for guid in self.0.iter().rev() {
+            count += guid.trailing_zeros();
+            if *guid != 0 {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Computes `self - rhs`, returning the wrapped result and whether an overflow occurred.
+    fn overflowing_sub(&self, rhs: &Euui) -> (Self, bool) {
+        let mut guids = [0u128; 4];
+        let mut borrow = false;
+        for i in (0..4).rev() {
+            let (difference, overflow_a) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (difference, overflow_b) = difference.overflowing_sub(borrow as u128);
+            guids[i] = difference;
+            borrow = overflow_a || overflow_b;
+        }
+        (Self(guids), borrow)
+    }
+
+    /// Computes `self + rhs`, returning the wrapped result and whether an overflow occurred.
+    fn overflowing_add(&self, rhs: &Euui) -> (Self, bool) {
+        let mut guids = [0u128; 4];
+        let mut carry = false;
+        for i in (0..4).rev() {
+            let (sum, overflow_a) = self.0[i].overflowing_add(rhs.0[i]);
+            let (sum, overflow_b) = sum.overflowing_add(carry as u128);
+            guids[i] = sum;
+            carry = overflow_a || overflow_b;
+        }
+        (Self(guids), carry)
+    }
+
+    /// Computes `self + rhs` as 512-bits unsigned integers.
+    ///
+    /// Returns [None] if the sum overflows.
+    pub fn checked_add(&self, rhs: &Euui) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes `self - rhs` as 512-bits unsigned integers.
+    ///
+    /// Returns [None] if `rhs` is greater than `self`.
+    pub fn checked_sub(&self, rhs: &Euui) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes `self * n` as a 512-bits unsigned integer.
+    ///
+    /// Returns [None] if the product overflows.
+    pub fn checked_mul_u64(&self, n: u64) -> Option<Self> {
+        let mut longs = self.to_be_longs();
+        let mut carry = 0u128;
+        for long in longs.iter_mut().rev() {
+            let wide = *long as u128 * n as u128 + carry;
+            *long = wide as u64;
+            carry = wide >> 64;
+        }
+        if carry == 0 { Some(Self::from_be_longs(longs)) } else { None }
+    }
+
+    /// Computes `self + n` as a 512-bits unsigned integer, propagating the carry only as far as needed.
+    ///
+    /// Returns [None] if the sum overflows.
+    pub fn checked_add_u64(&self, n: u64) -> Option<Self> {
+        let mut guids = self.0;
+        let (sum, mut carry) = guids[3].overflowing_add(n as u128);
+        guids[3] = sum;
+        for guid in guids[..3].iter_mut().rev() {
+            if !carry {
+                break;
+            }
+            (*guid, carry) = guid.overflowing_add(1);
+        }
+        if carry { None } else { Some(Self(guids)) }
+    }
+
+    /// Computes `self - n` as a 512-bits unsigned integer, propagating the borrow only as far as needed.
+    ///
+    /// Returns [None] if `n` is greater than `self`.
+    pub fn checked_sub_u64(&self, n: u64) -> Option<Self> {
+        let mut guids = self.0;
+        let (difference, mut borrow) = guids[3].overflowing_sub(n as u128);
+        guids[3] = difference;
+        for guid in guids[..3].iter_mut().rev() {
+            if !borrow {
+                break;
+            }
+            (*guid, borrow) = guid.overflowing_sub(1);
+        }
+        if borrow { None } else { Some(Self(guids)) }
+    }
+
+    /// Computes `self + rhs` as 512-bits unsigned integers, wrapping around at [Euui::MAX].
+    pub(crate) fn wrapping_add(&self, rhs: &Euui) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Computes `self - rhs` as 512-bits unsigned integers, wrapping around at zero.
+    pub(crate) fn wrapping_sub(&self, rhs: &Euui) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Computes `self * rhs` as 512-bits unsigned integers, keeping only the 512 low bits of the product.
+    pub(crate) fn wrapping_mul(&self, rhs: &Euui) -> Self {
+        let mut a = self.to_be_longs();
+        let mut b = rhs.to_be_longs();
+        a.reverse();
+        b.reverse();
+        let mut product = [0u64; 8];
+        for i in 0..8 {
+            let mut carry = 0u128;
+            for j in 0..8 - i {
+                let wide = a[i] as u128 * b[j] as u128 + product[i + j] as u128 + carry;
+                product[i + j] = wide as u64;
+                carry = wide >> 64;
+            }
+        }
+        product.reverse();
+        Self::from_be_longs(product)
+    }
+
+    /// Computes the two's complement negation `-self` of this 512-bits integer (`!self + 1`), wrapping around at zero.
+    ///
+    /// Zero and `1 << 511` are their own negations.
+    pub fn wrapping_neg(&self) -> Self {
+        (!*self).wrapping_add(&Self::from_u128(1))
+    }
+
+    /// Divides this EUUI by `rhs` as 512-bits unsigned integers, returning the quotient and the remainder.
+    ///
+    /// Returns [None] if `rhs` is zero.
+    fn checked_div_rem(&self, rhs: &Euui) -> Option<(Self, Self)> {
+        if rhs.0 == [0; 4] {
+            return None;
+        }
+        let mut quotient = [0u128; 4];
+        let mut remainder = Euui::default();
+        for index in 0..512 {
+            let (shifted, carry) = remainder.shl1(self.msb_bit(index));
+            remainder = shifted;
+            if carry || remainder >= *rhs {
+                remainder = remainder.overflowing_sub(rhs).0;
+                quotient[index / 128] |= 1 << (127 - index % 128);
+            }
+        }
+        Some((Self(quotient), remainder))
+    }
+
+    /// Compares this EUUI, as a 512-bits unsigned integer, with a u128.
+    pub fn cmp_u128(&self, value: u128) -> Ordering {
+        if self.0[..3] != [0; 3] { Ordering::Greater } else { self.0[3].cmp(&value) }
+    }
+
+    /// Returns the smallest EUUI of `euuis`.
+    ///
+    /// Returns [None] if `euuis` is empty.
+    pub fn min_of(euuis: &[Euui]) -> Option<Self> {
+        euuis.iter().min().copied()
+    }
+
+    /// Returns the greatest EUUI of `euuis`.
+    ///
+    /// Returns [None] if `euuis` is empty.
+    pub fn max_of(euuis: &[Euui]) -> Option<Self> {
+        euuis.iter().max().copied()
+    }
+
+    /// Returns `a` and `b` ordered smallest first, e.g. to use the pair as an undirected edge key.
+    pub fn ordered_pair(a: Euui, b: Euui) -> (Self, Self) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
+    /// Returns the greatest common divisor of this EUUI and `other` as 512-bits unsigned integers,
+    /// computed with the binary GCD (Stein's) algorithm.
+    ///
+    /// The GCD of zero and `other` is `other`.
+    pub fn gcd(&self, other: &Euui) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return *self | *other;
+        }
+        let shift = (*self | *other).trailing_zeros();
+        let mut a = *self >> self.trailing_zeros();
+        let mut b = *other;
+        loop {
+            b = b >> b.trailing_zeros();
+            if a > b {
+                std::mem::swap(&mut a, &mut b);
+            }
+            b = b.wrapping_sub(&a);
+            if b.is_zero() {
+                return a << shift;
+            }
+        }
+    }
+
+    /// Computes `(self * rhs) % modulus`, `self` and `rhs` being lower than `modulus`.
+    ///
+    /// The full-width product is used when it fits in 512 bits, otherwise `rhs` is added bit by bit.
+    fn mul_mod(&self, rhs: &Euui, modulus: &Euui) -> Self {
+        let zero = Self::zero();
+        if self.common_prefix_len(&zero) + rhs.common_prefix_len(&zero) >= 512 {
+            return self.wrapping_mul(rhs).checked_rem(modulus).expect("A non-zero modulus");
+        }
+        let mut product = zero;
+        for index in 0..512 {
+            let (doubled, carry) = product.shl1(false);
+            product = if carry || doubled >= *modulus { doubled.wrapping_sub(modulus) } else { doubled };
+            if rhs.msb_bit(index) {
+                let (sum, carry) = product.overflowing_add(self);
+                product = if carry || sum >= *modulus { sum.wrapping_sub(modulus) } else { sum };
+            }
+        }
+        product
+    }
+
+    /// Computes `self ^ exp % modulus` as 512-bits unsigned integers, by square-and-multiply.
+    ///
+    /// Only the significant bits of `exp` are processed : a small exponent costs a few rounds, not 512.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(&self, exp: &Euui, modulus: &Euui) -> Self {
+        let mut base = self.checked_rem(modulus).expect("Modulus must not be zero");
+        let mut result = Self::from_u128(1).checked_rem(modulus).unwrap();
+        for index in (exp.leading_zeros()..512).rev() {
+            if exp.msb_bit(index) {
+                result = result.mul_mod(&base, modulus);
+            }
+            base = base.mul_mod(&base, modulus);
+        }
+        result
+    }
+
+    /// Divides this EUUI by a u64, returning the quotient and the remainder.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        assert_ne!(divisor, 0, "Division by zero");
+        let mut longs = self.to_be_longs();
+        let mut remainder = 0u128;
+        for long in longs.iter_mut() {
+            let dividend = remainder << 64 | *long as u128;
+            *long = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (Self::from_be_longs(longs), remainder as u64)
+    }
+
+    /// Returns the bucket of this EUUI among `shards` buckets : the 512-bits value modulo `shards`.
+    ///
+    /// The result is deterministic and uniformly distributed for random EUUIs.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `shards` is zero.
+    pub fn shard(&self, shards: u32) -> u32 {
+        self.div_rem_u64(shards as u64).1 as u32
+    }
+
+    /// Divides this EUUI by `rhs` as 512-bits unsigned integers.
+    ///
+    /// Returns [None] if `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Euui) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Returns the remainder of the division of this EUUI by `rhs` as 512-bits unsigned integers.
+    ///
+    /// Returns [None] if `rhs` is zero.
+    pub fn checked_rem(&self, rhs: &Euui) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_wrapping_add_sub() {
+        let one = Euui::new(0, 0, 0, 1);
+        assert_eq!(Euui::MAX.wrapping_add(&one), Euui::zero());
+        assert_eq!(Euui::zero().wrapping_sub(&one), Euui::MAX);
+        assert_eq!(
+            Euui::new(0, 0, 0, u128::MAX).wrapping_add(&one),
+            Euui::new(0, 0, 1, 0)
+        );
+        let a = sample(1);
+        let b = sample(2);
+        assert_eq!(a.wrapping_add(&b).wrapping_sub(&b), a);
+        assert_eq!(a + b, a.wrapping_add(&b));
+    }
+
+    #[test]
+    fn test_wrapping_neg() {
+        assert_eq!(Euui::zero().wrapping_neg(), Euui::zero());
+        assert_eq!(Euui::from_u128(1).wrapping_neg(), Euui::MAX);
+        assert_eq!(Euui::MAX.wrapping_neg(), Euui::from_u128(1));
+        assert_eq!(Euui::new(1 << 127, 0, 0, 0).wrapping_neg(), Euui::new(1 << 127, 0, 0, 0));
+        let a = sample(3);
+        assert_eq!(a + a.wrapping_neg(), Euui::zero());
+        assert_eq!(a.wrapping_neg().wrapping_neg(), a);
+        assert_eq!(Euui::zero().wrapping_sub(&a), a.wrapping_neg());
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let one = Euui::new(0, 0, 0, 1);
+        assert_eq!(Euui::MAX.checked_add(&one), None);
+        assert_eq!(Euui::zero().checked_sub(&one), None);
+        let a = sample(4);
+        let b = sample(5);
+        let (low, high) = if a < b { (a, b) } else { (b, a) };
+        assert_eq!(high.checked_sub(&low), Some(high.wrapping_sub(&low)));
+        assert_eq!(Euui::new(0, 0, 0, u128::MAX).checked_add(&one), Some(Euui::new(0, 0, 1, 0)));
+    }
+
+    #[test]
+    fn test_checked_mul_u64() {
+        let euui = sample(6);
+        assert_eq!(euui.checked_mul_u64(1), Some(euui));
+        assert_eq!(euui.checked_mul_u64(0), Some(Euui::zero()));
+        assert_eq!(Euui::new(0, 0, 0, u128::MAX).checked_mul_u64(3), Some(Euui::new(0, 0, 2, u128::MAX - 2)));
+        assert_eq!(Euui::MAX.checked_mul_u64(2), None);
+        assert_eq!(Euui::new(1 << 64, 0, 0, 0).checked_mul_u64(1 << 63), Some(Euui::new(1 << 127, 0, 0, 0)));
+        assert_eq!(Euui::new(1 << 127, 0, 0, 0).checked_mul_u64(2), None);
+    }
+
+    #[test]
+    fn test_checked_add_sub_u64() {
+        let euui = Euui::new(0, 0, 0, 10);
+        assert_eq!(euui.checked_add_u64(5), Some(Euui::new(0, 0, 0, 15)));
+        assert_eq!(euui.checked_sub_u64(5), Some(Euui::new(0, 0, 0, 5)));
+
+        let edge = Euui::new(0, 7, u128::MAX, u128::MAX - 1);
+        assert_eq!(edge.checked_add_u64(3), Some(Euui::new(0, 8, 0, 1)));
+        assert_eq!(Euui::new(0, 8, 0, 1).checked_sub_u64(3), Some(edge));
+
+        assert_eq!(Euui::MAX.checked_add_u64(1), None);
+        assert_eq!(Euui::MAX.checked_add_u64(0), Some(Euui::MAX));
+        assert_eq!(Euui::zero().checked_sub_u64(1), None);
+        assert_eq!(Euui::new(0, 0, 0, 4).checked_sub_u64(5), None);
+
+        let sampled = sample(7);
+        assert_eq!(sampled.checked_add_u64(42), sampled.checked_add(&Euui::from_u128(42)));
+        assert_eq!(sampled.checked_sub_u64(42), sampled.checked_sub(&Euui::from_u128(42)));
+    }
+
+    #[test]
+    fn test_wrapping_mul() {
+        let a = Euui::new(0, 0, 0, u128::MAX);
+        assert_eq!(a.wrapping_mul(&a), Euui::new(0, 0, u128::MAX - 1, 1));
+        assert_eq!(Euui::MAX.wrapping_mul(&Euui::MAX), Euui::new(0, 0, 0, 1));
+        let b = sample(8);
+        let two = Euui::new(0, 0, 0, 2);
+        assert_eq!(b.wrapping_mul(&two), b + b);
+        assert_eq!(b * Euui::new(0, 0, 0, 1), b);
+        assert_eq!(b * Euui::zero(), Euui::zero());
+    }
+
+    #[test]
+    fn test_cmp_u128() {
+        assert_eq!(Euui::new(1, 0, 0, 0).cmp_u128(u128::MAX), Ordering::Greater);
+        assert_eq!(Euui::new(0, 0, 1, 0).cmp_u128(u128::MAX), Ordering::Greater);
+        assert_eq!(Euui::new(0, 0, 0, 42).cmp_u128(42), Ordering::Equal);
+        assert_eq!(Euui::new(0, 0, 0, 41).cmp_u128(42), Ordering::Less);
+        assert_eq!(Euui::new(0, 0, 0, 43).cmp_u128(42), Ordering::Greater);
+        assert_eq!(Euui::zero().cmp_u128(0), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_min_max_of() {
+        let euuis = [Euui::new(0, 1, 0, 0), Euui::new(0, 0, 0, 7), Euui::MAX, Euui::new(2, 0, 0, 0)];
+        assert_eq!(Euui::min_of(&euuis), Some(Euui::new(0, 0, 0, 7)));
+        assert_eq!(Euui::max_of(&euuis), Some(Euui::MAX));
+        let single = [sample(9)];
+        assert_eq!(Euui::min_of(&single), Some(single[0]));
+        assert_eq!(Euui::max_of(&single), Some(single[0]));
+        assert_eq!(Euui::min_of(&[]), None);
+        assert_eq!(Euui::max_of(&[]), None);
+    }
+
+    #[test]
+    fn test_ordered_pair() {
+        let a = sample(10);
+        let b = sample(11);
+        let pair = Euui::ordered_pair(a, b);
+        assert_eq!(Euui::ordered_pair(b, a), pair);
+        assert!(pair.0 <= pair.1);
+        assert_eq!(Euui::ordered_pair(Euui::MAX, Euui::zero()), (Euui::zero(), Euui::MAX));
+        assert_eq!(Euui::ordered_pair(a, a), (a, a));
+    }
+
+    #[test]
+    fn test_gcd() {
+        let a = sample(12);
+        assert_eq!(a.gcd(&Euui::zero()), a);
+        assert_eq!(Euui::zero().gcd(&a), a);
+        assert_eq!(Euui::zero().gcd(&Euui::zero()), Euui::zero());
+        assert_eq!(Euui::from_u128(48).gcd(&Euui::from_u128(180)), Euui::from_u128(12));
+
+        let factor = Euui::new(0, 0, 0x1234, 0x5678_9abc);
+        let x = factor.checked_mul_u64(6 * 35).unwrap();
+        let y = factor.checked_mul_u64(6 * 11).unwrap();
+        assert_eq!(x.gcd(&y), factor.checked_mul_u64(6).unwrap());
+        assert_eq!(y.gcd(&x), x.gcd(&y));
+
+        let one = Euui::from_u128(1);
+        assert_eq!(Euui::MAX.gcd(&Euui::MAX.wrapping_sub(&one)), one);
+        assert_eq!(Euui::new(1 << 127, 0, 0, 0).gcd(&Euui::from_u128(97)), one);
+        assert_eq!(Euui::new(1 << 127, 0, 0, 0).gcd(&Euui::new(0, 0, 1, 0)), Euui::new(0, 0, 1, 0));
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let n = Euui::from_u128;
+        assert_eq!(n(2).pow_mod(&n(10), &n(1000)), n(24));
+        assert_eq!(n(3).pow_mod(&n(0), &n(7)), n(1));
+        assert_eq!(n(3).pow_mod(&n(0), &n(1)), n(0));
+        assert_eq!(n(4).pow_mod(&n(13), &n(497)), n(445));
+        assert_eq!(n(2).pow_mod(&n(511), &Euui::MAX), Euui::new(1 << 127, 0, 0, 0));
+        assert_eq!(n(2).pow_mod(&n(512), &Euui::MAX), n(1));
+        assert_eq!(n(3).pow_mod(&Euui::MAX, &n(7)), n(6));
+        assert_eq!(n(3).pow_mod(&Euui::new(1, 0, 0, 0), &n(7)), n(4));
+
+        let modulus = Euui::MAX.wrapping_sub(&n(568));
+        let a = sample(13).checked_rem(&modulus).unwrap();
+        assert_eq!(a.pow_mod(&n(1), &modulus), a);
+        assert_eq!(a.pow_mod(&n(2), &modulus), a.mul_mod(&a, &modulus));
+        assert_eq!(
+            a.pow_mod(&n(5), &modulus),
+            a.pow_mod(&n(2), &modulus).mul_mod(&a.pow_mod(&n(3), &modulus), &modulus)
+        );
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        let modulus = Euui::new(0, 0, 1, 0);
+        let a = Euui::new(0, 0, 0, u128::MAX);
+        assert_eq!(a.mul_mod(&a, &modulus), Euui::from_u128(1));
+        let big = Euui::MAX.wrapping_sub(&Euui::from_u128(1));
+        assert_eq!(big.mul_mod(&big, &Euui::MAX), Euui::from_u128(1));
+        let half = Euui::new(1 << 127, 0, 0, 0);
+        assert_eq!(half.mul_mod(&Euui::from_u128(2), &Euui::MAX), Euui::from_u128(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_mod_zero_modulus() {
+        Euui::from_u128(2).pow_mod(&Euui::from_u128(2), &Euui::zero());
+    }
+
+    #[test]
+    fn test_div_rem_u64() {
+        let euui = sample(14);
+        let (quotient, remainder) = euui.div_rem_u64(1_000_000_007);
+        assert_eq!(
+            quotient * Euui::new(0, 0, 0, 1_000_000_007) + Euui::new(0, 0, 0, remainder as u128),
+            euui
+        );
+        assert_eq!(
+            Euui::MAX.div_rem_u64(u64::MAX),
+            (Euui::MAX.checked_div(&Euui::new(0, 0, 0, u64::MAX as u128)).unwrap(), 0)
+        );
+    }
+
+    #[test]
+    fn test_shard() {
+        let mut counts = [0u32; 8];
+        for n in 0..8000 {
+            let euui = sample(n);
+            assert_eq!(euui.shard(1), 0);
+            counts[euui.shard(8) as usize] += 1;
+        }
+        assert!(counts.iter().all(|count| (800..1200).contains(count)), "{:?}", counts);
+        assert_eq!(Euui::new(0, 0, 0, 13).shard(8), 5);
+    }
+
+    #[test]
+    fn test_checked_div_rem() {
+        let max = Euui::MAX;
+        let two = Euui::new(0, 0, 0, 2);
+        assert_eq!(
+            max.checked_div(&two),
+            Some(Euui::new(u128::MAX >> 1, u128::MAX, u128::MAX, u128::MAX))
+        );
+        assert_eq!(max.checked_rem(&two), Some(Euui::new(0, 0, 0, 1)));
+
+        let euui = sample(16);
+        assert_eq!(euui.checked_div(&euui), Some(Euui::new(0, 0, 0, 1)));
+        assert_eq!(euui.checked_rem(&euui), Some(Euui::default()));
+        assert_eq!(euui.checked_div(&Euui::default()), None);
+        assert_eq!(euui.checked_rem(&Euui::default()), None);
+
+        let a = Euui::new(0, 0, 1, 0);
+        let b = Euui::new(0, 0, 0, 3);
+        assert_eq!(a.checked_div(&b), Some(Euui::new(0, 0, 0, u128::MAX / 3)));
+        assert_eq!(a.checked_rem(&b), Some(Euui::new(0, 0, 0, 1)));
+        assert_eq!(b.checked_div(&a), Some(Euui::default()));
+        assert_eq!(b.checked_rem(&a), Some(b));
+    }
+}
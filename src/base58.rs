@@ -0,0 +1,80 @@
+use crate::{Euui, ParseEuuiError};
+
+/// The Bitcoin base58 alphabet.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+impl Euui {
+    /// Returns the base58 encoding (Bitcoin alphabet) of the 64 bytes of [Euui::to_be_bytes].
+    ///
+    /// As usual with base58, each leading zero byte is encoded as a leading `1`.
+    pub fn to_base58(&self) -> String {
+        let zeros = self.to_be_bytes().iter().take_while(|byte| **byte == 0).count();
+        let mut encoded = "1".repeat(zeros);
+        if zeros < 64 {
+            encoded.extend(self.to_fixed_base(58).iter().map(|digit| ALPHABET[*digit as usize] as char));
+        }
+        encoded
+    }
+
+    /// Parses a base58 string (Bitcoin alphabet) produced by [Euui::to_base58].
+    ///
+    /// Returns [ParseEuuiError::InvalidLength] if the string does not encode exactly 64 bytes.
+    pub fn from_base58(s: &str) -> Result<Self, ParseEuuiError> {
+        let zeros = s.chars().take_while(|c| *c == '1').count();
+        let digits = s
+            .chars()
+            .enumerate()
+            .skip(zeros)
+            .map(|(position, c)| {
+                ALPHABET
+                    .iter()
+                    .position(|symbol| *symbol as char == c)
+                    .map(|digit| digit as u8)
+                    .ok_or(ParseEuuiError::InvalidDigit { position, found: c })
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let euui = if digits.is_empty() { Euui::zero() } else { Euui::from_digits(&digits, 58)? };
+        let value_zeros = euui.to_be_bytes().iter().take_while(|byte| **byte == 0).count();
+        if zeros != value_zeros {
+            return Err(ParseEuuiError::InvalidLength(s.chars().count()));
+        }
+        Ok(euui)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Euui, ParseEuuiError};
+    use crate::tests::sample;
+
+    #[test]
+    fn test_base58_round_trip() {
+        let euui = sample(1).with_byte(0, 0xff).unwrap();
+        let encoded = euui.to_base58();
+        assert!(!encoded.starts_with('1'));
+        assert_eq!(Euui::from_base58(&encoded), Ok(euui));
+
+        let leading = sample(2).with_byte(0, 0).unwrap().with_byte(1, 0).unwrap().with_byte(2, 1).unwrap();
+        let encoded = leading.to_base58();
+        assert!(encoded.starts_with("11") && !encoded.starts_with("111"));
+        assert_eq!(Euui::from_base58(&encoded), Ok(leading));
+
+        assert_eq!(Euui::zero().to_base58(), "1".repeat(64));
+        assert_eq!(Euui::from_base58(&"1".repeat(64)), Ok(Euui::zero()));
+        assert_eq!(Euui::from_u128(57).to_base58(), format!("{}z", "1".repeat(63)));
+    }
+
+    #[test]
+    fn test_base58_errors() {
+        assert_eq!(
+            Euui::from_base58("10OI"),
+            Err(ParseEuuiError::InvalidDigit { position: 1, found: '0' })
+        );
+        assert_eq!(Euui::from_base58("1112"), Err(ParseEuuiError::InvalidLength(4)));
+        let encoded = sample(3).with_byte(0, 0xff).unwrap().to_base58();
+        assert_eq!(
+            Euui::from_base58(&format!("1{}", encoded)),
+            Err(ParseEuuiError::InvalidLength(encoded.len() + 1))
+        );
+    }
+}
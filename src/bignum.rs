@@ -0,0 +1,36 @@
+use crate::Euui;
+use ethnum::U256;
+
+impl Euui {
+    /// Provides a 512-bits EUUI from two 256-bits unsigned integers of the `ethnum` crate,
+    /// `high` holding #1 and #2, and `low` holding #3 and #4.
+    pub fn from_u256_pair(high: U256, low: U256) -> Self {
+        let (a, b) = high.into_words();
+        let (c, d) = low.into_words();
+        Self([a, b, c, d])
+    }
+
+    /// Returns this EUUI as two 256-bits unsigned integers of the `ethnum` crate : `(high, low)`.
+    pub fn to_u256_pair(&self) -> (U256, U256) {
+        (U256::from_words(self.0[0], self.0[1]), U256::from_words(self.0[2], self.0[3]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+    use ethnum::U256;
+
+    #[test]
+    fn test_u256_pair() {
+        let euui = sample(1);
+        let (high, low) = euui.to_u256_pair();
+        assert_eq!(Euui::from_u256_pair(high, low), euui);
+        assert_eq!(
+            Euui::new(0, 1, 0, 2).to_u256_pair(),
+            (U256::new(1), U256::new(2))
+        );
+        assert_eq!(Euui::new(1, 0, 0, 0).to_u256_pair().0, U256::ONE << 128);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::Euui;
+
+impl Euui {
+    /// Returns an EUUI whose `bits` least significant bits are set (all bits if `bits >= 512`).
+    pub(crate) fn low_bits_mask(bits: u32) -> Self {
+        Self::zero().map_words(|i, _| {
+            let word_bits = bits.saturating_sub(128 * (3 - i as u32)).min(128);
+            if word_bits == 128 { u128::MAX } else { (1u128 << word_bits) - 1 }
+        })
+    }
+
+    /// Returns the network address of this EUUI for a prefix of `prefix_bits` bits :
+    /// the `512 - prefix_bits` host bits are cleared.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `prefix_bits > 512`.
+    pub fn network_address(&self, prefix_bits: u32) -> Self {
+        assert!(prefix_bits <= 512, "A prefix cannot be longer than 512 bits");
+        *self & !Self::low_bits_mask(512 - prefix_bits)
+    }
+
+    /// Returns the broadcast address of this EUUI for a prefix of `prefix_bits` bits :
+    /// the `512 - prefix_bits` host bits are set.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `prefix_bits > 512`.
+    pub fn broadcast_address(&self, prefix_bits: u32) -> Self {
+        assert!(prefix_bits <= 512, "A prefix cannot be longer than 512 bits");
+        *self | Self::low_bits_mask(512 - prefix_bits)
+    }
+
+    /// Shifts this EUUI left by `n` bits, zeros filling the low bits. Returns zero if `n >= 512`.
+    pub(crate) fn shl_or_zero(&self, n: u32) -> Self {
+        let (words, bits) = ((n / 128) as usize, n % 128);
+        self.map_words(|i, _| {
+            let high = self.0.get(i + words).copied().unwrap_or(0);
+            let low = self.0.get(i + words + 1).copied().unwrap_or(0);
+            if bits == 0 { high } else { high << bits | low >> (128 - bits) }
+        })
+    }
+
+    /// Shifts this EUUI right by `n` bits, zeros filling the high bits. Returns zero if `n >= 512`.
+    pub(crate) fn shr_or_zero(&self, n: u32) -> Self {
+        let (words, bits) = ((n / 128) as usize, n % 128);
+        self.map_words(|i, _| {
+            let low = i.checked_sub(words).map_or(0, |j| self.0[j]);
+            let high = i.checked_sub(words + 1).map_or(0, |j| self.0[j]);
+            if bits == 0 { low } else { low >> bits | high << (128 - bits) }
+        })
+    }
+
+    /// Splits this EUUI around the bit `bit` (as in [Euui::bit]) : the first EUUI holds the `bit` most significant bits,
+    /// shifted down to the least significant side, and the second one holds the `512 - bit` other bits.
+    ///
+    /// See [Euui::join_at_bit] for the reverse operation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `bit > 512`.
+    pub fn split_at_bit(&self, bit: u32) -> (Self, Self) {
+        assert!(bit <= 512, "Cannot split an EUUI after its 512th bit");
+        (self.shr_or_zero(512 - bit), *self & Self::low_bits_mask(512 - bit))
+    }
+
+    /// Provides an EUUI made of the `low_bits` least significant bits of `low`, with `high` above them.
+    ///
+    /// Returns [None] if `low_bits > 512` or if `high` does not fit in `512 - low_bits` bits.
+    pub fn join_at_bit(high: &Euui, low: &Euui, low_bits: u32) -> Option<Self> {
+        if low_bits > 512 || high.common_prefix_len(&Self::zero()) < low_bits {
+            return None;
+        }
+        Some(high.shl_or_zero(low_bits) | *low & Self::low_bits_mask(low_bits))
+    }
+
+    /// Shifts this EUUI left by `n` bits.
+    ///
+    /// Returns [None] if `n >= 512`, like the `checked_shl` of integers. The `<<` operator returns zero instead.
+    pub fn checked_shl(&self, n: u32) -> Option<Self> {
+        if n < 512 { Some(self.shl_or_zero(n)) } else { None }
+    }
+
+    /// Shifts this EUUI right by `n` bits.
+    ///
+    /// Returns [None] if `n >= 512`, like the `checked_shr` of integers. The `>>` operator returns zero instead.
+    pub fn checked_shr(&self, n: u32) -> Option<Self> {
+        if n < 512 { Some(self.shr_or_zero(n)) } else { None }
+    }
+
+    /// Gets the bit at `index`, `0` being the most significant bit (of the word 0)
+    /// and `511` the least significant one (of the word 3).
+    ///
+    /// Returns [None] if index >= 512.
+    pub fn bit(&self, index: usize) -> Option<bool> {
+        if index >= 512 {
+            return None;
+        }
+        Some(self.0[index / 128] >> (127 - index % 128) & 1 == 1)
+    }
+
+    /// Returns a new EUUI where the bit at `index` (as in [Euui::bit]) is replaced by `value`.
+    ///
+    /// Returns [None] if index >= 512.
+    pub fn with_bit(&self, index: usize, value: bool) -> Option<Self> {
+        if index >= 512 {
+            return None;
+        }
+        let mask = 1u128 << (127 - index % 128);
+        let mut guids = self.0;
+        if value { guids[index / 128] |= mask } else { guids[index / 128] &= !mask }
+        Some(Self(guids))
+    }
+
+    /// Returns an iterator over the 512 bits of this EUUI, from the most significant one to the least significant one.
+    pub fn bits(&self) -> impl Iterator<Item = bool> {
+        let euui = *self;
+        (0..512).map(move |index| euui.bit(index).unwrap())
+    }
+
+    /// Returns the number of set bits in this EUUI.
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|guid| guid.count_ones()).sum()
+    }
+
+    /// Returns the XOR of all `ids`, an order-independent aggregate of a set of EUUIs (zero if `ids` is empty).
+    ///
+    /// Note that an EUUI present twice cancels itself out.
+    pub fn xor_all(ids: &[Euui]) -> Self {
+        ids.iter().fold(Self::zero(), |aggregate, id| aggregate ^ *id)
+    }
+
+    /// Returns the parity of this EUUI : the XOR of its 512 bits, ie. true if [Euui::count_ones] is odd.
+    pub fn parity(&self) -> bool {
+        self.0.iter().fold(0, |parity, guid| parity ^ guid).count_ones() & 1 == 1
+    }
+
+    /// Returns a new EUUI keeping only the bits of this EUUI which are set in `mask` (same as `self & mask`).
+    pub fn retain_bits(&self, mask: &Euui) -> Self {
+        *self & *mask
+    }
+
+    /// Returns a new EUUI where the bits set in `mask` are cleared (same as `self & !mask`).
+    pub fn clear_bits(&self, mask: &Euui) -> Self {
+        *self & !*mask
+    }
+
+    /// Returns a rough estimate of the entropy of this EUUI, in bits, to spot obviously broken generators.
+    ///
+    /// This is the Shannon entropy of the byte frequencies (at most 6 bits per byte, as there are only 64 bytes)
+    /// multiplied by 64. A zero EUUI reports `0.0`, while a random EUUI usually reports about 365.
+    pub fn approx_entropy_bits(&self) -> f64 {
+        let mut counts = [0u32; 256];
+        for byte in self.to_be_bytes() {
+            counts[byte as usize] += 1;
+        }
+        let per_byte: f64 = counts
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let p = *count as f64 / 64.0;
+                -p * p.log2()
+            })
+            .sum();
+        per_byte * 64.0
+    }
+
+    /// Returns the number of leading bits (from the most significant one) shared by this EUUI and `other`.
+    ///
+    /// Returns 512 if both EUUIs are equal.
+    pub fn common_prefix_len(&self, other: &Euui) -> u32 {
+        let mut length = 0;
+        for i in 0..4 {
+            let difference = self.0[i] ^ other.0[i];
+            length += difference.leading_zeros();
+            if difference != 0 {
+                break;
+            }
+        }
+        length
+    }
+
+    /// Returns true if this EUUI falls within the range of `prefix`/`prefix_bits`,
+    /// ie. if its `prefix_bits` most significant bits are the same as those of `prefix`.
+    pub fn in_subnet(&self, prefix: &Euui, prefix_bits: u32) -> bool {
+        self.common_prefix_len(prefix) >= prefix_bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+
+    #[test]
+    fn test_low_bits_mask() {
+        assert_eq!(Euui::low_bits_mask(0), Euui::zero());
+        assert_eq!(Euui::low_bits_mask(1), Euui::new(0, 0, 0, 1));
+        assert_eq!(Euui::low_bits_mask(130), Euui::new(0, 0, 3, u128::MAX));
+        assert_eq!(Euui::low_bits_mask(512), Euui::MAX);
+        assert_eq!(Euui::low_bits_mask(600), Euui::MAX);
+    }
+
+    #[test]
+    fn test_network_and_broadcast_addresses() {
+        let euui = sample(1);
+        let network = euui.network_address(130);
+        let broadcast = euui.broadcast_address(130);
+        assert_eq!(network, Euui::new(euui.u128(0).unwrap(), euui.u128(1).unwrap() & (3 << 126), 0, 0));
+        assert_eq!(
+            broadcast,
+            Euui::new(euui.u128(0).unwrap(), euui.u128(1).unwrap() | (u128::MAX >> 2), u128::MAX, u128::MAX)
+        );
+        assert!(network <= euui && euui <= broadcast);
+        assert_eq!(euui.network_address(512), euui);
+        assert_eq!(euui.broadcast_address(512), euui);
+        assert_eq!(euui.network_address(0), Euui::zero());
+        assert_eq!(euui.broadcast_address(0), Euui::MAX);
+        assert_eq!(Euui::MAX.network_address(511), Euui::MAX.wrapping_sub(&Euui::from_u128(1)));
+    }
+
+    #[test]
+    fn test_bit() {
+        let euui = Euui::new(1 << 127, 0, 0, 1);
+        assert_eq!(euui.bit(0), Some(true));
+        assert_eq!(euui.bit(1), Some(false));
+        assert_eq!(euui.bit(511), Some(true));
+        assert_eq!(euui.bit(512), None);
+        assert_eq!(euui.with_bit(0, false), Some(Euui::new(0, 0, 0, 1)));
+        assert_eq!(euui.with_bit(128, true), Some(Euui::new(1 << 127, 1 << 127, 0, 1)));
+        assert_eq!(euui.with_bit(511, true), Some(euui));
+        assert_eq!(euui.with_bit(512, true), None);
+    }
+
+    #[test]
+    fn test_bits() {
+        let euui = sample(2);
+        assert_eq!(euui.bits().count(), 512);
+        assert_eq!(euui.bits().filter(|bit| *bit).count() as u32, euui.count_ones());
+        let rebuilt = euui.bits().enumerate()
+            .fold(Euui::zero(), |rebuilt, (index, bit)| rebuilt.with_bit(index, bit).unwrap());
+        assert_eq!(rebuilt, euui);
+        assert_eq!(Euui::zero().count_ones(), 0);
+        assert_eq!(Euui::MAX.count_ones(), 512);
+        assert_eq!(Euui::new(0, 0, 0, 1).bits().position(|bit| bit), Some(511));
+    }
+
+    #[test]
+    fn test_xor_all() {
+        let ids = [sample(3), sample(4), sample(5)];
+        let aggregate = Euui::xor_all(&ids);
+        assert_eq!(aggregate, ids[0] ^ ids[1] ^ ids[2]);
+        assert_eq!(Euui::xor_all(&[ids[2], ids[0], ids[1]]), aggregate);
+        assert_eq!(Euui::xor_all(&[ids[1], ids[2], ids[0]]), aggregate);
+        assert_eq!(Euui::xor_all(&[ids[0], ids[0]]), Euui::zero());
+        assert_eq!(Euui::xor_all(&[]), Euui::zero());
+    }
+
+    #[test]
+    fn test_parity() {
+        assert!(!Euui::zero().parity());
+        assert!(Euui::new(0, 0, 1 << 64, 0).parity());
+        assert!(!Euui::MAX.parity());
+        assert!(!Euui::new(1, 0, 0, 1).parity());
+        let euui = sample(6);
+        assert_eq!(euui.parity(), euui.count_ones() % 2 == 1);
+        assert_ne!(euui.parity(), euui.with_bit(7, !euui.bit(7).unwrap()).unwrap().parity());
+    }
+
+    #[test]
+    fn test_retain_and_clear_bits() {
+        let euui = sample(7);
+        assert_eq!(euui.retain_bits(&Euui::MAX), euui);
+        assert_eq!(euui.clear_bits(&Euui::MAX), Euui::zero());
+        assert_eq!(euui.retain_bits(&Euui::zero()), Euui::zero());
+        assert_eq!(euui.clear_bits(&Euui::zero()), euui);
+        let mask = Euui::new(0, u128::MAX, 0, 0xff);
+        assert_eq!(euui.retain_bits(&mask) | euui.clear_bits(&mask), euui);
+        assert_eq!(Euui::new(1, 2, 3, 0x1ff).clear_bits(&mask), Euui::new(1, 0, 3, 0x100));
+    }
+
+    #[test]
+    fn test_split_at_bit() {
+        let euui = Euui::new(1, 2, 3, 4);
+        assert_eq!(euui.split_at_bit(256), (Euui::new(0, 0, 1, 2), Euui::new(0, 0, 3, 4)));
+        assert_eq!(euui.split_at_bit(0), (Euui::zero(), euui));
+        assert_eq!(euui.split_at_bit(512), (euui, Euui::zero()));
+        assert_eq!(Euui::MAX.split_at_bit(1), (Euui::new(0, 0, 0, 1), Euui::MAX >> 1));
+    }
+
+    #[test]
+    fn test_join_at_bit() {
+        let euui = sample(8);
+        for bit in [0, 1, 127, 128, 256, 300, 511, 512] {
+            let (high, low) = euui.split_at_bit(bit);
+            assert_eq!(Euui::join_at_bit(&high, &low, 512 - bit), Some(euui));
+        }
+        assert_eq!(Euui::join_at_bit(&Euui::new(0, 0, 0, 3), &Euui::MAX, 4), Some(Euui::new(0, 0, 0, 0x3f)));
+        assert_eq!(Euui::join_at_bit(&Euui::new(0, 0, 0, 1), &Euui::zero(), 511), Some(Euui::new(1 << 127, 0, 0, 0)));
+        assert_eq!(Euui::join_at_bit(&Euui::new(0, 0, 0, 2), &Euui::zero(), 511), None);
+        assert_eq!(Euui::join_at_bit(&Euui::zero(), &euui, 512), Some(euui));
+        assert_eq!(Euui::join_at_bit(&Euui::zero(), &euui, 513), None);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let one = Euui::from_u128(1);
+        assert_eq!(one << 1, Euui::from_u128(2));
+        assert_eq!(one << 128, Euui::new(0, 0, 1, 0));
+        assert_eq!(one << 130, Euui::new(0, 0, 4, 0));
+        assert_eq!(one << 511, Euui::new(1 << 127, 0, 0, 0));
+        assert_eq!(one << 512, Euui::zero());
+        assert_eq!(Euui::new(1 << 127, 0, 0, 0) >> 511, one);
+        assert_eq!(Euui::new(0, 0, 4, 0) >> 130, one);
+        assert_eq!(Euui::MAX >> 512, Euui::zero());
+
+        let euui = sample(9);
+        assert_eq!(euui << 0, euui);
+        assert_eq!(euui >> 0, euui);
+        assert_eq!((euui << 200) >> 200, euui & (Euui::MAX >> 200));
+        assert_eq!((euui >> 77) << 77, euui & (Euui::MAX << 77));
+        assert_eq!(euui << 1, euui + euui);
+    }
+
+    #[test]
+    fn test_checked_shifts() {
+        let euui = sample(10);
+        assert_eq!(euui.checked_shl(511), Some(euui << 511));
+        assert_eq!(euui.checked_shr(511), Some(euui >> 511));
+        assert_eq!(euui.checked_shl(512), None);
+        assert_eq!(euui.checked_shr(512), None);
+    }
+
+    #[test]
+    fn test_approx_entropy_bits() {
+        assert_eq!(Euui::zero().approx_entropy_bits(), 0.0);
+        assert_eq!(Euui::MAX.approx_entropy_bits(), 0.0);
+        assert_eq!(Euui::new(0, 0, u128::MAX, u128::MAX).approx_entropy_bits(), 64.0);
+        assert!(sample(11).approx_entropy_bits() > 300.0);
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let euui = sample(12);
+        assert_eq!(euui.common_prefix_len(&euui), 512);
+        assert_eq!(euui.common_prefix_len(&!euui), 0);
+
+        let a = Euui::new(1 << 127, 0, 0, 0);
+        assert_eq!(a.common_prefix_len(&Euui::zero()), 0);
+
+        let b = Euui::new(7, 0xff00, 0, 0);
+        let c = Euui::new(7, 0xf000, 0, 0);
+        assert_eq!(b.common_prefix_len(&c), 128 + 116);
+        assert_eq!(Euui::new(0, 0, 0, 1).common_prefix_len(&Euui::zero()), 511);
+    }
+
+    #[test]
+    fn test_in_subnet() {
+        let prefix = Euui::new(0xabcd, 1 << 127, 0, 0);
+        let inside = Euui::new(0xabcd, (1 << 127) | 0xff, 42, 7);
+        assert!(inside.in_subnet(&prefix, 129));
+        assert!(prefix.broadcast_address(129).in_subnet(&prefix, 129));
+        assert!(prefix.network_address(129).in_subnet(&prefix, 129));
+        assert!(!Euui::new(0xabcd, 0, 0, 0).in_subnet(&prefix, 129));
+        assert!(!prefix.broadcast_address(129).wrapping_add(&Euui::from_u128(1)).in_subnet(&prefix, 129));
+        assert!(sample(13).in_subnet(&prefix, 0));
+        assert!(prefix.in_subnet(&prefix, 512));
+    }
+}
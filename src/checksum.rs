@@ -0,0 +1,71 @@
+//! Checksum algorithms used by the checked textual encodings.
+
+/// CRC-32 (IEEE 802.3, reflected polynomial `0xEDB88320`), as used by zlib and PNG.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// CRC-16/CCITT-FALSE (polynomial `0x1021`, initial value `0xFFFF`, not reflected).
+pub(crate) fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc = 0xffffu16;
+    for byte in bytes {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Multiplies `x` by the generator `2` in GF(32), reduced by the polynomial `x^5 + x^2 + 1`.
+fn gf32_double(x: u8) -> u8 {
+    let doubled = x << 1;
+    if doubled & 0x20 != 0 { doubled ^ 0x25 } else { doubled }
+}
+
+/// Runs the Damm algorithm over base-32 digits and returns the interim digit.
+///
+/// The quasigroup used is `a * b = 2a + b` in GF(32), which is totally anti-symmetric :
+/// every single-digit error and every adjacent transposition changes the result.
+pub(crate) fn damm32<I: IntoIterator<Item = u8>>(digits: I) -> u8 {
+    digits.into_iter().fold(0, |interim, digit| gf32_double(interim) ^ digit)
+}
+
+/// Returns the base-32 check digit to append to `digits` so that [damm32] over the whole sequence is `0`.
+pub(crate) fn damm32_check_digit<I: IntoIterator<Item = u8>>(digits: I) -> u8 {
+    gf32_double(damm32(digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checksum::{crc16, crc32, damm32, damm32_check_digit};
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(&[]), 0);
+    }
+
+    #[test]
+    fn test_crc16_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x29b1);
+        assert_eq!(crc16(&[]), 0xffff);
+    }
+
+    #[test]
+    fn test_damm32() {
+        let digits = [3u8, 17, 31, 0, 9, 9, 24];
+        let check = damm32_check_digit(digits);
+        assert_eq!(damm32(digits.iter().copied().chain([check])), 0);
+
+        let transposed = [3u8, 31, 17, 0, 9, 9, 24];
+        assert_ne!(damm32(transposed.iter().copied().chain([check])), 0);
+    }
+}
@@ -0,0 +1,1099 @@
+use crate::checksum::{crc32, damm32, damm32_check_digit};
+use crate::{Euui, ParseEuuiError};
+use std::iter::Peekable;
+
+/// The letter case accepted for hexadecimal digits by [Euui::from_hex_strict] and [Euui::parse_with].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum HexCase {
+    /// Only `0-9` and `a-f`.
+    Lower,
+    /// Only `0-9` and `A-F`.
+    Upper,
+    /// Both lowercase and uppercase digits, even mixed.
+    #[default]
+    Any,
+}
+
+impl HexCase {
+    /// Returns the value of `c` if it is an hexadecimal digit of this case.
+    fn digit(self, c: char) -> Option<u32> {
+        match (self, c) {
+            (HexCase::Lower, 'A'..='F') | (HexCase::Upper, 'a'..='f') => None,
+            _ => c.to_digit(16),
+        }
+    }
+}
+
+/// The options of the hexadecimal parser [Euui::parse_with].
+///
+/// The default options only accept exactly 128 hexadecimal digits of any case, as [str::parse].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct HexParseOptions {
+    /// Accepts a leading `0x` or `0X`.
+    pub allow_prefix: bool,
+    /// Ignores the `-`, `_` and whitespace characters between digits (e.g. the [Euui::format] output).
+    pub allow_separators: bool,
+    /// The letter case accepted for the digits.
+    pub required_case: HexCase,
+    /// Accepts less than 128 digits, the missing most significant digits being zeros.
+    pub padding: bool,
+}
+
+impl HexParseOptions {
+    /// Returns true if `c` is a separator ignored with these options.
+    fn is_separator(&self, c: char) -> bool {
+        self.allow_separators && (c == '-' || c == '_' || c.is_whitespace())
+    }
+}
+
+/// The Crockford base32 alphabet.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The 45 symbols of the QR code alphanumeric mode.
+const QR_ALPHANUMERIC: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Decodes a Crockford base32 symbol, case-insensitively and with the `I`/`L` -> `1` and `O` -> `0` aliases.
+fn crockford_digit(c: char) -> Option<u8> {
+    match c.to_ascii_uppercase() {
+        'I' | 'L' => Some(1),
+        'O' => Some(0),
+        c => CROCKFORD.iter().position(|symbol| *symbol as char == c).map(|i| i as u8),
+    }
+}
+
+/// Parses a `{8-4-4-4-12}` GUID, with or without braces, into a u128.
+fn from_braced_guid(s: &str) -> Result<u128, ParseEuuiError> {
+    let chars: Vec<char> = s.chars().collect();
+    let (offset, inner) = match chars.as_slice() {
+        ['{', inner @ .., '}'] => (1, inner),
+        inner => (0, inner),
+    };
+    if inner.len() != 36 {
+        return Err(ParseEuuiError::InvalidLength(chars.len()));
+    }
+    let mut guid = 0u128;
+    for (i, c) in inner.iter().copied().enumerate() {
+        let position = i + offset;
+        if matches!(i, 8 | 13 | 18 | 23) {
+            if c != '-' {
+                return Err(ParseEuuiError::InvalidDigit { position, found: c });
+            }
+            continue;
+        }
+        let digit = c.to_digit(16).ok_or(ParseEuuiError::InvalidDigit { position, found: c })?;
+        guid = guid << 4 | digit as u128;
+    }
+    Ok(guid)
+}
+
+/// Returns the 103 base32 digits of a 512-bits value (the first digit holds only 2 bits).
+fn to_base32_digits(euui: &Euui) -> [u8; 103] {
+    let bytes = euui.to_be_bytes();
+    let mut digits = [0u8; 103];
+    for (k, digit) in digits.iter_mut().enumerate() {
+        for j in (k * 5) as isize - 3..(k * 5) as isize + 2 {
+            let bit = j >= 0 && bytes[j as usize / 8] >> (7 - j as usize % 8) & 1 == 1;
+            *digit = *digit << 1 | bit as u8;
+        }
+    }
+    digits
+}
+
+/// Rebuilds a 512-bits value from 103 base32 digits.
+fn from_base32_digits(digits: &[u8]) -> Result<Euui, ParseEuuiError> {
+    if digits[0] > 3 {
+        return Err(ParseEuuiError::Overflow);
+    }
+    let mut bytes = [0u8; 64];
+    for (k, digit) in digits.iter().enumerate() {
+        for (n, j) in ((k * 5) as isize - 3..(k * 5) as isize + 2).enumerate() {
+            if j >= 0 && digit >> (4 - n) & 1 == 1 {
+                bytes[j as usize / 8] |= 1 << (7 - j as usize % 8);
+            }
+        }
+    }
+    Ok(Euui::from_be_bytes(bytes))
+}
+
+/// Decodes Crockford base32 symbols into digits, `offset` being the position of the first symbol in the input.
+fn base32_digits<I: IntoIterator<Item = char>>(chars: I, offset: usize) -> Result<Vec<u8>, ParseEuuiError> {
+    chars
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| crockford_digit(c).ok_or(ParseEuuiError::InvalidDigit { position: offset + i, found: c }))
+        .collect()
+}
+
+/// Decodes a Crockford base32 string into digits, checking the expected length.
+fn parse_base32_digits(s: &str, length: usize) -> Result<Vec<u8>, ParseEuuiError> {
+    let count = s.chars().count();
+    if count != length {
+        return Err(ParseEuuiError::InvalidLength(count));
+    }
+    base32_digits(s.chars(), 0)
+}
+
+/// Encodes a u128 as a 26 symbols ULID string.
+fn to_ulid(word: u128) -> String {
+    (0..26).rev().map(|k| CROCKFORD[(word >> (k * 5)) as usize & 0x1f] as char).collect()
+}
+
+/// Decodes 26 ULID symbols into a u128, `offset` being the position of the first symbol in the input.
+fn from_ulid(chars: &[char], offset: usize) -> Result<u128, ParseEuuiError> {
+    let digits = base32_digits(chars.iter().copied(), offset)?;
+    if digits[0] > 7 {
+        return Err(ParseEuuiError::Overflow);
+    }
+    Ok(digits.iter().fold(0, |word, digit| word << 5 | *digit as u128))
+}
+
+/// Advances `chars` past any whitespace, leaving the next significant character to be peeked.
+fn skip_whitespace<I: Iterator<Item = (usize, char)>>(chars: &mut Peekable<I>) {
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+}
+
+impl Euui {
+    /// Returns the digits of this EUUI, as a 512-bits unsigned integer, in the given `base` (most significant first).
+    ///
+    /// There is no leading zero digit, except for the zero EUUI which is `[0]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `base` is not in `2..=256`.
+    pub fn to_fixed_base(&self, base: u32) -> Vec<u8> {
+        assert!((2..=256).contains(&base), "The base must be in 2..=256");
+        let mut digits = Vec::new();
+        let mut value = *self;
+        loop {
+            let (quotient, digit) = value.div_rem_u64(base as u64);
+            digits.push(digit as u8);
+            value = quotient;
+            if value.is_zero() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+
+    /// Provides a 512-bits EUUI from its `digits` in the given `base` (most significant first).
+    ///
+    /// Returns [ParseEuuiError::DigitOutOfRange] if a digit is not lower than `base`,
+    /// [ParseEuuiError::Overflow] if the value does not fit in 512 bits,
+    /// and [ParseEuuiError::InvalidLength] if there is no digit at all.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `base` is not in `2..=256`.
+    pub fn from_digits(digits: &[u8], base: u32) -> Result<Self, ParseEuuiError> {
+        assert!((2..=256).contains(&base), "The base must be in 2..=256");
+        if digits.is_empty() {
+            return Err(ParseEuuiError::InvalidLength(0));
+        }
+        digits.iter().enumerate().try_fold(Euui::zero(), |value, (position, digit)| {
+            if *digit as u32 >= base {
+                return Err(ParseEuuiError::DigitOutOfRange { position, digit: *digit });
+            }
+            value
+                .checked_mul_u64(base as u64)
+                .and_then(|value| value.checked_add(&Euui::new(0, 0, 0, *digit as u128)))
+                .ok_or(ParseEuuiError::Overflow)
+        })
+    }
+
+    /// Parses a string of digits in the given `radix` (e.g. 10 for decimal) as a 512-bits unsigned integer.
+    ///
+    /// Letters are accepted in both cases for radixes above 10. Leading zeros are allowed.
+    /// Returns [ParseEuuiError::Overflow] if the value is 2^512 or more.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `radix` is not in `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseEuuiError> {
+        assert!((2..=36).contains(&radix), "The radix must be in 2..=36");
+        let digits = s
+            .chars()
+            .enumerate()
+            .map(|(position, c)| {
+                c.to_digit(radix)
+                    .map(|digit| digit as u8)
+                    .ok_or(ParseEuuiError::InvalidDigit { position, found: c })
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        Self::from_digits(&digits, radix)
+    }
+
+    /// Returns the decimal string of this EUUI as a 512-bits unsigned integer, without leading zeros.
+    pub fn to_decimal_string(&self) -> String {
+        self.to_fixed_base(10).iter().map(|digit| (b'0' + digit) as char).collect()
+    }
+
+    /// Parses a decimal string as a 512-bits unsigned integer, clamping to [Euui::MAX] instead of overflowing.
+    ///
+    /// Returns [ParseEuuiError::InvalidDigit] for any character which is not a decimal digit,
+    /// and [ParseEuuiError::InvalidLength] if the string is empty.
+    pub fn from_decimal_saturating(s: &str) -> Result<Self, ParseEuuiError> {
+        if s.is_empty() {
+            return Err(ParseEuuiError::InvalidLength(0));
+        }
+        let mut value = Some(Self::zero());
+        for (position, c) in s.chars().enumerate() {
+            let digit = c.to_digit(10).ok_or(ParseEuuiError::InvalidDigit { position, found: c })?;
+            value = value
+                .and_then(|value| value.checked_mul_u64(10))
+                .and_then(|value| value.checked_add_u64(digit as u64));
+        }
+        Ok(value.unwrap_or(Self::MAX))
+    }
+
+    /// Parses a raw hexadecimal string of exactly 128 digits, only accepting the letter `case` given.
+    ///
+    /// A letter of the wrong case is rejected as a [ParseEuuiError::InvalidDigit].
+    pub fn from_hex_strict(s: &str, case: HexCase) -> Result<Self, ParseEuuiError> {
+        Self::parse_with(s, HexParseOptions { required_case: case, ..Default::default() })
+    }
+
+    /// Returns this EUUI as a lowercase base36 number (`0-9` and `a-z`), without leading zeros (at most 100 characters).
+    pub fn to_base36(&self) -> String {
+        self.to_fixed_base(36).iter().map(|digit| char::from_digit(*digit as u32, 36).expect("A base36 digit")).collect()
+    }
+
+    /// Parses a base36 number, like the output of [Euui::to_base36]. Letters are accepted in both cases.
+    ///
+    /// Returns [ParseEuuiError::Overflow] if the value does not fit in 512 bits.
+    pub fn from_base36(s: &str) -> Result<Self, ParseEuuiError> {
+        Self::from_str_radix(s, 36)
+    }
+
+    /// Returns [Euui::to_base36] split into DNS labels of at most 63 characters (so 1 or 2 labels),
+    /// e.g. to embed this EUUI in a domain name.
+    ///
+    /// Joining the labels gives back [Euui::to_base36].
+    pub fn to_dns_labels(&self) -> Vec<String> {
+        let base36: Vec<char> = self.to_base36().chars().collect();
+        base36.chunks(63).map(|label| label.iter().collect()).collect()
+    }
+
+    /// Parses exactly 128 lowercase hexadecimal digits, which is the strictest parser of EUUIs :
+    /// no whitespace, prefix, separator nor uppercase digit is accepted.
+    ///
+    /// The characters are checked before the length, so the error is a [ParseEuuiError::InvalidDigit]
+    /// at the first unexpected character if any, and a [ParseEuuiError::InvalidLength] otherwise.
+    pub fn checked_from_str_exact(s: &str) -> Result<Self, ParseEuuiError> {
+        if let Some((position, found)) = s.chars().enumerate().find(|(_, c)| !matches!(c, '0'..='9' | 'a'..='f')) {
+            return Err(ParseEuuiError::InvalidDigit { position, found });
+        }
+        Self::from_hex_strict(s, HexCase::Lower)
+    }
+
+    /// Parses an hexadecimal string as configured by `options`.
+    ///
+    /// Returns a [ParseEuuiError::InvalidLength] with the number of digits found (separators and prefix excluded)
+    /// if there are more than 128 digits, or less than 128 digits without [HexParseOptions::padding],
+    /// and a [ParseEuuiError::InvalidDigit] at its position in `s` for any other unexpected character.
+    pub fn parse_with(s: &str, options: HexParseOptions) -> Result<Self, ParseEuuiError> {
+        let offset = match s.get(..2) {
+            Some("0x" | "0X") if options.allow_prefix => 2,
+            _ => 0,
+        };
+        let body = &s[offset..];
+        let length = body.chars().filter(|c| !options.is_separator(*c)).count();
+        if length > 128 || length == 0 || (length < 128 && !options.padding) {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        let mut guids = [0u128; 4];
+        let mut i = 128 - length;
+        for (position, c) in body.chars().enumerate() {
+            if options.is_separator(c) {
+                continue;
+            }
+            let digit = options.required_case.digit(c)
+                .ok_or(ParseEuuiError::InvalidDigit { position: position + offset, found: c })?;
+            guids[i / 32] = guids[i / 32] << 4 | digit as u128;
+            i += 1;
+        }
+        Ok(Self(guids))
+    }
+
+    /// Parses an hexadecimal string of at most 128 digits (of any case) whose trailing zeros may have been truncated :
+    /// the string is right-padded with `0` up to 128 digits, so an empty string is the zero EUUI.
+    ///
+    /// See [HexParseOptions::padding] to left-pad instead.
+    pub fn from_hex_right_padded(s: &str) -> Result<Self, ParseEuuiError> {
+        let length = s.chars().count();
+        if length > 128 {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        Self::from_hex_strict(&format!("{:0<128}", s), HexCase::Any)
+    }
+
+    /// Returns true if `s` is exactly 128 hexadecimal digits (lowercase or uppercase),
+    /// ie. if it can be parsed as an EUUI with [str::parse].
+    pub fn is_valid_hex(s: &str) -> bool {
+        s.len() == 128 && s.bytes().all(|byte| byte.is_ascii_hexdigit())
+    }
+
+    /// Returns a ULID-like representation of this EUUI : each u128 is encoded as a 26 symbols
+    /// Crockford base32 ULID, and the 4 ULIDs are joined by "-" (107 characters).
+    ///
+    /// A ULID only holds 128 bits, so each 256-bits half of the EUUI is written as a pair of ULIDs.
+    ///
+    /// ## Example
+    ///
+    /// ```txt
+    /// 01GZ6Y0Y6YXV3C9XGZ0ZAGWQ7F-7ZZZZZZZZZZZZZZZZZZZZZZZZZ-00000000000000000000000000-0000000000000000000000000A
+    /// ```
+    pub fn to_ulid_pair(&self) -> String {
+        self.0.map(to_ulid).join("-")
+    }
+
+    /// Parses a string produced by [Euui::to_ulid_pair].
+    ///
+    /// Decoding is case-insensitive and accepts the `I`, `L` and `O` aliases.
+    pub fn from_ulid_pair(s: &str) -> Result<Self, ParseEuuiError> {
+        let length = s.chars().count();
+        if length != 107 {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        let chars: Vec<char> = s.chars().collect();
+        let mut guids = [0u128; 4];
+        for (i, guid) in guids.iter_mut().enumerate() {
+            let start = i * 27;
+            if i > 0 && chars[start - 1] != '-' {
+                return Err(ParseEuuiError::InvalidDigit { position: start - 1, found: chars[start - 1] });
+            }
+            *guid = from_ulid(&chars[start..start + 26], start)?;
+        }
+        Ok(Self(guids))
+    }
+
+    /// Returns the 4 u128s composing this EUUI as Windows registry-style GUIDs :
+    /// 32 uppercase hexadecimal digits grouped as `{8-4-4-4-12}` (38 characters).
+    ///
+    /// ## Example
+    ///
+    /// ```txt
+    /// {2F8596CC-2F3B-3DA9-ADF2-0CF9413104AB}
+    /// ```
+    pub fn to_braced_guids(&self) -> [String; 4] {
+        self.0.map(|guid| {
+            let hex = format!("{:032X}", guid);
+            format!("{{{}-{}-{}-{}-{}}}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+        })
+    }
+
+    /// Parses 4 Windows registry-style GUIDs (as produced by [Euui::to_braced_guids]) into the 4 u128s of an EUUI.
+    ///
+    /// The braces are optional and the digits may be of any case.
+    /// The positions of the errors are relative to the GUID string in fault.
+    pub fn from_braced_guids(guids: [&str; 4]) -> Result<Self, ParseEuuiError> {
+        let mut words = [0u128; 4];
+        for (word, guid) in words.iter_mut().zip(guids) {
+            *word = from_braced_guid(guid)?;
+        }
+        Ok(Self(words))
+    }
+
+    /// Returns the 4 u128s composing this EUUI as lowercase hexadecimal strings of 32 digits.
+    ///
+    /// The words are the 4 parts of the [Display](std::fmt::Display) output (see [Euui::from_hex_words] for the reverse operation).
+    pub fn to_hex_words(&self) -> [String; 4] {
+        self.0.map(|guid| format!("{:032x}", guid))
+    }
+
+    /// Parses 4 hexadecimal strings of exactly 32 digits (of any case) into the 4 u128s of an EUUI.
+    ///
+    /// The positions of the errors are relative to the word string in fault.
+    pub fn from_hex_words(words: [&str; 4]) -> Result<Self, ParseEuuiError> {
+        let mut guids = [0u128; 4];
+        for (guid, word) in guids.iter_mut().zip(words) {
+            let length = word.chars().count();
+            if length != 32 {
+                return Err(ParseEuuiError::InvalidLength(length));
+            }
+            for (position, c) in word.chars().enumerate() {
+                let digit = HexCase::Any.digit(c).ok_or(ParseEuuiError::InvalidDigit { position, found: c })?;
+                *guid = *guid << 4 | digit as u128;
+            }
+        }
+        Ok(Self(guids))
+    }
+
+    /// Returns the Crockford base32 encoding of this EUUI, as 103 uppercase symbols.
+    ///
+    /// The value is encoded as a big-endian number, so the first symbol is always between `0` and `3`.
+    pub fn to_base32_crockford(&self) -> String {
+        to_base32_digits(self).iter().map(|digit| CROCKFORD[*digit as usize] as char).collect()
+    }
+
+    /// Parses a Crockford base32 string of 103 symbols produced by [Euui::to_base32_crockford].
+    ///
+    /// Decoding is case-insensitive and accepts the `I`, `L` and `O` aliases.
+    pub fn from_base32_crockford(s: &str) -> Result<Self, ParseEuuiError> {
+        from_base32_digits(&parse_base32_digits(s, 103)?)
+    }
+
+    /// Returns [Euui::to_base32_crockford] followed by a check symbol, 104 symbols in total.
+    ///
+    /// The check symbol is computed with the Damm algorithm over the base32 digits,
+    /// so any single-symbol error and any adjacent transposition is detected by [Euui::from_base32_crockford_checked].
+    pub fn to_base32_crockford_checked(&self) -> String {
+        let digits = to_base32_digits(self);
+        let check = damm32_check_digit(digits);
+        digits
+            .iter()
+            .chain([check].iter())
+            .map(|digit| CROCKFORD[*digit as usize] as char)
+            .collect()
+    }
+
+    /// Parses a string produced by [Euui::to_base32_crockford_checked].
+    ///
+    /// Returns [ParseEuuiError::ChecksumMismatch] if the check symbol does not match.
+    pub fn from_base32_crockford_checked(s: &str) -> Result<Self, ParseEuuiError> {
+        let digits = parse_base32_digits(s, 104)?;
+        if damm32(digits.iter().copied()) != 0 {
+            return Err(ParseEuuiError::ChecksumMismatch);
+        }
+        from_base32_digits(&digits[..103])
+    }
+
+    /// Returns this EUUI as a big-endian base45 number over the QR code alphanumeric alphabet
+    /// (`0-9`, `A-Z`, space and `$%*+-./:`), which QR codes store more densely than hexadecimal.
+    ///
+    /// The output is always 94 symbols long, left-padded with `0`.
+    pub fn to_qr_alphanumeric(&self) -> String {
+        let digits = self.to_fixed_base(45);
+        let padding = "0".repeat(94 - digits.len());
+        padding + &digits.iter().map(|digit| QR_ALPHANUMERIC[*digit as usize] as char).collect::<String>()
+    }
+
+    /// Parses a string produced by [Euui::to_qr_alphanumeric].
+    ///
+    /// Returns [ParseEuuiError::InvalidLength] if the string is not 94 symbols long,
+    /// and [ParseEuuiError::Overflow] if the value does not fit in 512 bits.
+    pub fn from_qr_alphanumeric(s: &str) -> Result<Self, ParseEuuiError> {
+        let length = s.chars().count();
+        if length != 94 {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        let digits = s
+            .chars()
+            .enumerate()
+            .map(|(position, c)| {
+                QR_ALPHANUMERIC
+                    .iter()
+                    .position(|symbol| *symbol as char == c)
+                    .map(|digit| digit as u8)
+                    .ok_or(ParseEuuiError::InvalidDigit { position, found: c })
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        Self::from_digits(&digits, 45)
+    }
+
+    /// Returns the 64 bytes of [Euui::to_be_bytes] as 64 emoji, the byte `n` being the code point `U+1F400 + n`
+    /// (from 🐀 to 📿, in the "Miscellaneous Symbols and Pictographs" block).
+    pub fn to_emoji(&self) -> String {
+        self.u8_lanes().map(|byte| char::from_u32(0x1f400 + byte as u32).expect("A valid code point")).collect()
+    }
+
+    /// Parses a string produced by [Euui::to_emoji].
+    ///
+    /// Returns [ParseEuuiError::InvalidLength] if the string is not 64 characters long.
+    pub fn from_emoji(s: &str) -> Result<Self, ParseEuuiError> {
+        let length = s.chars().count();
+        if length != 64 {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        let mut bytes = [0u8; 64];
+        for (position, (byte, c)) in bytes.iter_mut().zip(s.chars()).enumerate() {
+            *byte = (c as u32)
+                .checked_sub(0x1f400)
+                .and_then(|offset| u8::try_from(offset).ok())
+                .ok_or(ParseEuuiError::InvalidDigit { position, found: c })?;
+        }
+        Ok(Self::from_be_bytes(bytes))
+    }
+
+    /// Returns the 64 bytes of [Euui::to_be_bytes] as a JSON array of numbers, like `[18,52,...]`.
+    pub fn to_json_u8_array(&self) -> String {
+        let numbers: Vec<String> = self.u8_lanes().map(|byte| byte.to_string()).collect();
+        format!("[{}]", numbers.join(","))
+    }
+
+    /// Parses a JSON array of 64 numbers in `0..=255`, like the output of [Euui::to_json_u8_array].
+    ///
+    /// Whitespace is allowed around brackets, numbers and commas, but not inside a number.
+    /// Returns [ParseEuuiError::InvalidLength] with the count of numbers if there are not exactly 64 of them,
+    /// and [ParseEuuiError::Overflow] if a number is greater than 255.
+    pub fn from_json_u8_array(s: &str) -> Result<Self, ParseEuuiError> {
+        let mut chars = s.chars().enumerate().peekable();
+        let mut bytes = Vec::with_capacity(64);
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some((_, '[')) => {}
+            Some((position, found)) => return Err(ParseEuuiError::InvalidDigit { position, found }),
+            None => return Err(ParseEuuiError::InvalidLength(0)),
+        }
+        skip_whitespace(&mut chars);
+        if chars.peek().map(|(_, c)| *c) == Some(']') {
+            chars.next();
+        } else {
+            loop {
+                skip_whitespace(&mut chars);
+                let mut number: Option<u32> = None;
+                while let Some((_, c)) = chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+                    let digit = c.to_digit(10).expect("An ASCII digit");
+                    let value = number.unwrap_or(0) * 10 + digit;
+                    if value > 255 {
+                        return Err(ParseEuuiError::Overflow);
+                    }
+                    number = Some(value);
+                    chars.next();
+                }
+                skip_whitespace(&mut chars);
+                match (number, chars.next()) {
+                    (Some(number), Some((_, ','))) => bytes.push(number as u8),
+                    (Some(number), Some((_, ']'))) => {
+                        bytes.push(number as u8);
+                        break;
+                    }
+                    (_, Some((position, found))) => return Err(ParseEuuiError::InvalidDigit { position, found }),
+                    (_, None) => return Err(ParseEuuiError::InvalidLength(bytes.len())),
+                }
+            }
+        }
+        skip_whitespace(&mut chars);
+        if let Some((position, found)) = chars.next() {
+            return Err(ParseEuuiError::InvalidDigit { position, found });
+        }
+        match bytes.try_into() {
+            Ok(bytes) => Ok(Self::from_be_bytes(bytes)),
+            Err(bytes) => Err(ParseEuuiError::InvalidLength(bytes.len())),
+        }
+    }
+
+    /// Returns the raw hexadecimal string of this EUUI followed by its CRC-32 as 8 hexadecimal digits.
+    ///
+    /// The CRC-32 is computed over the 64 bytes of [Euui::to_be_bytes], the result is 136 characters wide.
+    /// Use [Euui::from_string_with_checksum] to parse it back and detect transcription errors.
+    pub fn to_string_with_checksum(&self) -> String {
+        format!("{}{:08x}", self, crc32(&self.to_be_bytes()))
+    }
+
+    /// Parses a string produced by [Euui::to_string_with_checksum].
+    ///
+    /// Returns [ParseEuuiError::ChecksumMismatch] if the trailing CRC-32 does not match the EUUI.
+    pub fn from_string_with_checksum(s: &str) -> Result<Self, ParseEuuiError> {
+        let length = s.chars().count();
+        if length != 136 {
+            return Err(ParseEuuiError::InvalidLength(length));
+        }
+        let (split, _) = s.char_indices().nth(128).expect("The length is checked");
+        let (body, checksum) = s.split_at(split);
+        let euui: Euui = body.parse()?;
+        let checksum = checksum.chars().enumerate().try_fold(0u32, |crc, (i, c)| {
+            let digit = c.to_digit(16).ok_or(ParseEuuiError::InvalidDigit { position: 128 + i, found: c })?;
+            Ok(crc << 4 | digit)
+        })?;
+        if checksum != crc32(&euui.to_be_bytes()) {
+            return Err(ParseEuuiError::ChecksumMismatch);
+        }
+        Ok(euui)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Euui, ParseEuuiError};
+    use crate::{HexCase, HexParseOptions};
+    use crate::tests::sample;
+
+    #[test]
+    fn test_to_fixed_base() {
+        let mut bytes = sample(1).to_be_bytes();
+        bytes[0] = 0;
+        bytes[1] = 0;
+        bytes[2] |= 1;
+        let euui = Euui::from_be_bytes(bytes);
+        assert_eq!(euui.to_fixed_base(256), bytes[2..].to_vec());
+        assert_eq!(Euui::zero().to_fixed_base(256), vec![0]);
+        assert_eq!(Euui::new(0, 0, 0, 5).to_fixed_base(2), vec![1, 0, 1]);
+        assert_eq!(Euui::MAX.to_fixed_base(2), vec![1; 512]);
+
+        let decimal: Vec<u8> = euui.to_decimal_string().bytes().map(|b| b - b'0').collect();
+        assert_eq!(euui.to_fixed_base(10), decimal);
+    }
+
+    #[test]
+    fn test_from_digits() {
+        let euui = sample(2);
+        for base in [2, 7, 10, 16, 36, 58, 255, 256] {
+            assert_eq!(Euui::from_digits(&euui.to_fixed_base(base), base), Ok(euui));
+        }
+        assert_eq!(Euui::from_digits(&[0, 0, 1, 0, 1], 2), Ok(Euui::new(0, 0, 0, 5)));
+        assert_eq!(
+            Euui::from_digits(&[1, 2], 2),
+            Err(ParseEuuiError::DigitOutOfRange { position: 1, digit: 2 })
+        );
+        assert_eq!(Euui::from_digits(&[], 2), Err(ParseEuuiError::InvalidLength(0)));
+
+        let mut overflow = Euui::MAX.to_fixed_base(10);
+        assert_eq!(Euui::from_digits(&overflow, 10), Ok(Euui::MAX));
+        *overflow.last_mut().unwrap() += 1;
+        assert_eq!(Euui::from_digits(&overflow, 10), Err(ParseEuuiError::Overflow));
+        assert_eq!(Euui::from_digits(&[1; 513], 2), Err(ParseEuuiError::Overflow));
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        let max = "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084095";
+        let overflow = "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084096";
+        assert_eq!(Euui::from_str_radix(max, 10), Ok(Euui::MAX));
+        assert_eq!(Euui::from_str_radix(overflow, 10), Err(ParseEuuiError::Overflow));
+        assert_eq!(Euui::from_str_radix(&format!("0{}", max), 10), Ok(Euui::MAX));
+
+        let euui = sample(3);
+        assert_eq!(Euui::from_str_radix(&euui.to_decimal_string(), 10), Ok(euui));
+        assert_eq!(Euui::from_str_radix(&euui.to_string(), 16), Ok(euui));
+        assert_eq!(Euui::from_str_radix("zZ", 36), Ok(Euui::from_u128(36 * 35 + 35)));
+        assert_eq!(
+            Euui::from_str_radix("12a", 10),
+            Err(ParseEuuiError::InvalidDigit { position: 2, found: 'a' })
+        );
+        assert_eq!(Euui::from_str_radix("", 10), Err(ParseEuuiError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_to_decimal_string() {
+        assert_eq!(Euui::zero().to_decimal_string(), "0");
+        assert_eq!(Euui::new(0, 0, 0, u128::MAX).to_decimal_string(), u128::MAX.to_string());
+        assert_eq!(
+            Euui::MAX.to_decimal_string(),
+            "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084095"
+        );
+    }
+
+    #[test]
+    fn test_from_decimal_saturating() {
+        let euui = sample(4);
+        assert_eq!(Euui::from_decimal_saturating(&euui.to_decimal_string()), Ok(euui));
+        assert_eq!(Euui::from_decimal_saturating("0042"), Ok(Euui::from_u128(42)));
+        let max = Euui::MAX.to_decimal_string();
+        assert_eq!(Euui::from_decimal_saturating(&max), Ok(Euui::MAX));
+        assert_eq!(Euui::from_decimal_saturating(&format!("{}0", max)), Ok(Euui::MAX));
+        assert_eq!(Euui::from_decimal_saturating(&"9".repeat(1000)), Ok(Euui::MAX));
+        assert_eq!(
+            Euui::from_decimal_saturating(&format!("{}x", "9".repeat(200))),
+            Err(ParseEuuiError::InvalidDigit { position: 200, found: 'x' })
+        );
+        assert_eq!(Euui::from_decimal_saturating(""), Err(ParseEuuiError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_base36() {
+        let euui = sample(5);
+        assert_eq!(Euui::from_base36(&euui.to_base36()), Ok(euui));
+        assert_eq!(Euui::from_base36(&euui.to_base36().to_uppercase()), Ok(euui));
+        assert_eq!(Euui::zero().to_base36(), "0");
+        assert_eq!(Euui::from_u128(36 * 35 + 10).to_base36(), "za");
+        assert_eq!(
+            Euui::MAX.to_base36(),
+            "14plki42mdv1mt36i2rnak3ginnt5vcx207hpuf9x0vj6i1i7h29nu12wls3ulfv1yyabi94ua3wauamsxz4snwv27fya36hqdj3"
+        );
+        let overflow = "14plki42mdv1mt36i2rnak3ginnt5vcx207hpuf9x0vj6i1i7h29nu12wls3ulfv1yyabi94ua3wauamsxz4snwv27fya36hqdj4";
+        assert_eq!(Euui::from_base36(overflow), Err(ParseEuuiError::Overflow));
+    }
+
+    #[test]
+    fn test_dns_labels() {
+        let labels = Euui::MAX.to_dns_labels();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].len(), 63);
+        assert_eq!(labels[1].len(), 37);
+        assert_eq!(labels.concat(), Euui::MAX.to_base36());
+        assert_eq!(Euui::from_u128(35).to_dns_labels(), vec!["z".to_string()]);
+
+        let euui = sample(6);
+        let labels = euui.to_dns_labels();
+        assert!(labels.iter().all(|label| label.len() <= 63 && label.chars().all(|c| c.is_ascii_alphanumeric())));
+        assert_eq!(Euui::from_base36(&labels.concat()), Ok(euui));
+    }
+
+    #[test]
+    fn test_qr_alphanumeric() {
+        let euui = sample(7);
+        let encoded = euui.to_qr_alphanumeric();
+        assert_eq!(encoded.len(), 94);
+        assert_eq!(Euui::from_qr_alphanumeric(&encoded), Ok(euui));
+
+        assert_eq!(Euui::zero().to_qr_alphanumeric(), "0".repeat(94));
+        assert_eq!(Euui::new(0, 0, 0, 45 * 44 + 36).to_qr_alphanumeric(), format!("{}: ", "0".repeat(92)));
+        assert_eq!(
+            Euui::MAX.to_qr_alphanumeric(),
+            "2HQ.4:IE$N4A LBTKP0JP*%0I:IC7IXL45982IBSTGT7PM%ZFUZTIVM70I$JNBLE3%I20EA4 M3*FJ51:*H0KWBC2*PE2U"
+        );
+        assert_eq!(Euui::from_qr_alphanumeric(&Euui::MAX.to_qr_alphanumeric()), Ok(Euui::MAX));
+
+        assert_eq!(Euui::from_qr_alphanumeric(&encoded[1..]), Err(ParseEuuiError::InvalidLength(93)));
+        assert_eq!(Euui::from_qr_alphanumeric(&":".repeat(94)), Err(ParseEuuiError::Overflow));
+        assert_eq!(
+            Euui::from_qr_alphanumeric(&format!("{}a", "0".repeat(93))),
+            Err(ParseEuuiError::InvalidDigit { position: 93, found: 'a' })
+        );
+    }
+
+    #[test]
+    fn test_emoji() {
+        for n in 0..100 {
+            let euui = sample(n);
+            let emoji = euui.to_emoji();
+            assert_eq!(emoji.chars().count(), 64);
+            assert_eq!(Euui::from_emoji(&emoji), Ok(euui));
+        }
+        assert_eq!(Euui::zero().to_emoji(), "🐀".repeat(64));
+        assert_eq!(Euui::MAX.to_emoji(), "📿".repeat(64));
+        assert_eq!(Euui::from_emoji(&"🐀".repeat(63)), Err(ParseEuuiError::InvalidLength(63)));
+        assert_eq!(
+            Euui::from_emoji(&format!("{}a", "🐀".repeat(63))),
+            Err(ParseEuuiError::InvalidDigit { position: 63, found: 'a' })
+        );
+        assert_eq!(
+            Euui::from_emoji(&format!("😀{}", "🐀".repeat(63))),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: '😀' })
+        );
+    }
+
+    #[test]
+    fn test_json_u8_array() {
+        let euui = sample(9);
+        let json = euui.to_json_u8_array();
+        assert_eq!(Euui::from_json_u8_array(&json), Ok(euui));
+        assert_eq!(Euui::from_json_u8_array(&format!(" {} ", json.replace(',', " , "))), Ok(euui));
+
+        let zero = Euui::zero().to_json_u8_array();
+        assert_eq!(zero, format!("[{}0]", "0,".repeat(63)));
+        let one = Euui::from_u128(0x1234).to_json_u8_array();
+        assert!(one.ends_with(",0,18,52]"));
+
+        assert_eq!(Euui::from_json_u8_array("[]"), Err(ParseEuuiError::InvalidLength(0)));
+        assert_eq!(Euui::from_json_u8_array("[1,2]"), Err(ParseEuuiError::InvalidLength(2)));
+        assert_eq!(
+            Euui::from_json_u8_array(&zero.replacen('0', "256", 1)),
+            Err(ParseEuuiError::Overflow)
+        );
+        assert_eq!(
+            Euui::from_json_u8_array("[1,,2]"),
+            Err(ParseEuuiError::InvalidDigit { position: 3, found: ',' })
+        );
+        assert_eq!(
+            Euui::from_json_u8_array(&format!("{}]", zero)),
+            Err(ParseEuuiError::InvalidDigit { position: zero.len(), found: ']' })
+        );
+    }
+
+    #[test]
+    fn test_json_u8_array_whitespace() {
+        let zero = Euui::zero().to_json_u8_array();
+        assert_eq!(Euui::from_json_u8_array(&format!("\n{}\t", zero.replace(',', " ,\n"))), Ok(Euui::zero()));
+        assert_eq!(Euui::from_json_u8_array(&format!("[ {} ]", &zero[1..zero.len() - 1])), Ok(Euui::zero()));
+        assert_eq!(
+            Euui::from_json_u8_array(&zero.replacen('0', "2 5", 1)),
+            Err(ParseEuuiError::InvalidDigit { position: 3, found: '5' })
+        );
+        assert_eq!(
+            Euui::from_json_u8_array(&zero.replacen('0', "1\n0\n0", 1)),
+            Err(ParseEuuiError::InvalidDigit { position: 3, found: '0' })
+        );
+    }
+
+    #[test]
+    fn test_from_hex_strict() {
+        let euui = sample(10);
+        let lower = euui.to_string();
+        let upper = lower.to_uppercase();
+        assert_eq!(Euui::from_hex_strict(&lower, HexCase::Lower), Ok(euui));
+        assert_eq!(Euui::from_hex_strict(&upper, HexCase::Upper), Ok(euui));
+        assert_eq!(Euui::from_hex_strict(&upper, HexCase::Any), Ok(euui));
+        assert_eq!(Euui::from_hex_strict(&lower, HexCase::Any), Ok(euui));
+
+        let upper = "A".repeat(128);
+        assert_eq!(
+            Euui::from_hex_strict(&upper, HexCase::Lower),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: 'A' })
+        );
+        assert_eq!(
+            Euui::from_hex_strict(&upper.to_lowercase(), HexCase::Upper),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: 'a' })
+        );
+        assert!(Euui::from_hex_strict(&upper, HexCase::Any).is_ok());
+    }
+
+    #[test]
+    fn test_checked_from_str_exact() {
+        let euui = sample(11);
+        let hex = euui.to_string();
+        assert_eq!(Euui::checked_from_str_exact(&hex), Ok(euui));
+        assert_eq!(
+            Euui::checked_from_str_exact(&format!(" {}", hex)),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: ' ' })
+        );
+        assert_eq!(
+            Euui::checked_from_str_exact(&format!("{}\n", hex)),
+            Err(ParseEuuiError::InvalidDigit { position: 128, found: '\n' })
+        );
+        assert_eq!(
+            Euui::checked_from_str_exact(&format!("{}A{}", &hex[..5], &hex[6..])),
+            Err(ParseEuuiError::InvalidDigit { position: 5, found: 'A' })
+        );
+        assert_eq!(
+            Euui::checked_from_str_exact(&format!("0x{}", &hex[2..])),
+            Err(ParseEuuiError::InvalidDigit { position: 1, found: 'x' })
+        );
+        assert_eq!(
+            Euui::checked_from_str_exact(&euui.format()),
+            Err(ParseEuuiError::InvalidDigit { position: 32, found: '-' })
+        );
+        assert_eq!(Euui::checked_from_str_exact(&hex[1..]), Err(ParseEuuiError::InvalidLength(127)));
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let euui = sample(12);
+        let hex = euui.to_string();
+        let defaults = HexParseOptions::default();
+        assert_eq!(Euui::parse_with(&hex, defaults), Ok(euui));
+        assert_eq!(Euui::parse_with(&format!("0x{}", hex), defaults), Err(ParseEuuiError::InvalidLength(130)));
+        assert_eq!(Euui::parse_with(&euui.format(), defaults), Err(ParseEuuiError::InvalidLength(131)));
+        assert_eq!(Euui::parse_with("ff", defaults), Err(ParseEuuiError::InvalidLength(2)));
+
+        let prefixed = HexParseOptions { allow_prefix: true, ..defaults };
+        assert_eq!(Euui::parse_with(&format!("0x{}", hex), prefixed), Ok(euui));
+        assert_eq!(Euui::parse_with(&format!("0X{}", hex), prefixed), Ok(euui));
+        assert_eq!(Euui::parse_with(&hex, prefixed), Ok(euui));
+
+        let separated = HexParseOptions { allow_separators: true, ..defaults };
+        assert_eq!(Euui::parse_with(&euui.format(), separated), Ok(euui));
+        assert_eq!(Euui::parse_with(&euui.format_with('_', ' '), separated), Ok(euui));
+        assert_eq!(
+            Euui::parse_with(&format!("{}.{}", &hex[..32], &hex[33..]), separated),
+            Err(ParseEuuiError::InvalidDigit { position: 32, found: '.' })
+        );
+
+        let upper = HexParseOptions { required_case: HexCase::Upper, ..defaults };
+        assert_eq!(Euui::parse_with(&hex.to_uppercase(), upper), Ok(euui));
+        assert_eq!(
+            Euui::parse_with(&"a".repeat(128), upper),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: 'a' })
+        );
+
+        let padded = HexParseOptions { padding: true, ..defaults };
+        assert_eq!(Euui::parse_with("ff", padded), Ok(Euui::new(0, 0, 0, 0xff)));
+        assert_eq!(Euui::parse_with(&hex, padded), Ok(euui));
+        assert_eq!(Euui::parse_with("", padded), Err(ParseEuuiError::InvalidLength(0)));
+        assert_eq!(Euui::parse_with(&format!("0{}", hex), padded), Err(ParseEuuiError::InvalidLength(129)));
+
+        let lenient = HexParseOptions { allow_prefix: true, allow_separators: true, required_case: HexCase::Any, padding: true };
+        assert_eq!(Euui::parse_with("0x1-0000_0000 ff", lenient), Ok(Euui::new(0, 0, 0, 0x100_0000_00ff)));
+        assert_eq!(
+            Euui::parse_with("0x1-0000_g", lenient),
+            Err(ParseEuuiError::InvalidDigit { position: 9, found: 'g' })
+        );
+        assert_eq!(Euui::parse_with("0x", lenient), Err(ParseEuuiError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_from_hex_right_padded() {
+        let euui = sample(13);
+        assert_eq!(Euui::from_hex_right_padded(&euui.to_string()), Ok(euui));
+        assert_eq!(Euui::from_hex_right_padded("ab"), Ok(Euui::new(0xab << 120, 0, 0, 0)));
+        assert_eq!(Euui::from_hex_right_padded(&"F".repeat(33)), Ok(Euui::new(u128::MAX, 0xf << 124, 0, 0)));
+        assert_eq!(Euui::from_hex_right_padded(""), Ok(Euui::zero()));
+        assert_eq!(
+            Euui::from_hex_right_padded(&format!("{}0", euui)),
+            Err(ParseEuuiError::InvalidLength(129))
+        );
+        assert_eq!(
+            Euui::from_hex_right_padded("abz"),
+            Err(ParseEuuiError::InvalidDigit { position: 2, found: 'z' })
+        );
+    }
+
+    #[test]
+    fn test_is_valid_hex() {
+        let hex = sample(14).to_string();
+        assert!(Euui::is_valid_hex(&hex));
+        assert!(Euui::is_valid_hex(&hex.to_uppercase()));
+        assert!(!Euui::is_valid_hex(&hex[1..]));
+        assert!(!Euui::is_valid_hex(&format!("{}0", hex)));
+        assert!(!Euui::is_valid_hex(""));
+        assert!(!Euui::is_valid_hex(&format!("g{}", &hex[1..])));
+        assert!(!Euui::is_valid_hex(&format!("é{}", &hex[2..])));
+        assert!(!Euui::is_valid_hex(&sample(15).format()));
+    }
+
+    #[test]
+    fn test_checksum_round_trip() {
+        let euui = sample(16);
+        let string = euui.to_string_with_checksum();
+        assert_eq!(string.len(), 136);
+        assert!(string.starts_with(&euui.to_string()));
+        assert_eq!(Euui::from_string_with_checksum(&string), Ok(euui));
+    }
+
+    #[test]
+    fn test_checksum_detects_typo() {
+        let euui = sample(17);
+        let mut chars: Vec<char> = euui.to_string_with_checksum().chars().collect();
+        chars[10] = if chars[10] == '0' { '1' } else { '0' };
+        let typo: String = chars.into_iter().collect();
+        assert_eq!(
+            Euui::from_string_with_checksum(&typo),
+            Err(ParseEuuiError::ChecksumMismatch)
+        );
+        assert_eq!(
+            Euui::from_string_with_checksum(&euui.to_string()),
+            Err(ParseEuuiError::InvalidLength(128))
+        );
+        assert_eq!(
+            Euui::from_string_with_checksum(&format!("{}0000000x", euui)),
+            Err(ParseEuuiError::InvalidDigit { position: 135, found: 'x' })
+        );
+    }
+
+    #[test]
+    fn test_base32_crockford() {
+        let euui = sample(18);
+        let string = euui.to_base32_crockford();
+        assert_eq!(string.len(), 103);
+        assert_eq!(Euui::from_base32_crockford(&string), Ok(euui));
+        assert_eq!(Euui::from_base32_crockford(&string.to_lowercase()), Ok(euui));
+
+        let max = Euui::new(u128::MAX, u128::MAX, u128::MAX, u128::MAX);
+        assert_eq!(max.to_base32_crockford(), format!("3{}", "Z".repeat(102)));
+        assert_eq!(
+            Euui::from_base32_crockford(&"Z".repeat(103)),
+            Err(ParseEuuiError::Overflow)
+        );
+        assert_eq!(Euui::default().to_base32_crockford(), "0".repeat(103));
+        assert_eq!(
+            Euui::from_base32_crockford(&"U".repeat(103)),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: 'U' })
+        );
+    }
+
+    #[test]
+    fn test_base32_crockford_checked() {
+        let euui = Euui::new(0x0123456789abcdef, u128::MAX / 3, 42, 7);
+        let string = euui.to_base32_crockford_checked();
+        assert_eq!(string.len(), 104);
+        assert!(string.starts_with(&euui.to_base32_crockford()));
+        assert_eq!(Euui::from_base32_crockford_checked(&string), Ok(euui));
+
+        let mut chars: Vec<char> = string.chars().collect();
+        let i = (1..102).find(|i| chars[*i] != chars[*i + 1]).unwrap();
+        chars.swap(i, i + 1);
+        let transposed: String = chars.into_iter().collect();
+        assert_eq!(
+            Euui::from_base32_crockford_checked(&transposed),
+            Err(ParseEuuiError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_to_braced_guids() {
+        let euui = Euui::new(0x2f8596cc2f3b3da9adf20cf9413104ab, 0, u128::MAX, 1);
+        let guids = euui.to_braced_guids();
+        assert_eq!(guids[0], "{2F8596CC-2F3B-3DA9-ADF2-0CF9413104AB}");
+        assert_eq!(guids[1], "{00000000-0000-0000-0000-000000000000}");
+        assert_eq!(guids[2], "{FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF}");
+        assert_eq!(guids[3], "{00000000-0000-0000-0000-000000000001}");
+        assert!(sample(19).to_braced_guids().iter().all(|guid| guid.len() == 38));
+    }
+
+    #[test]
+    fn test_from_braced_guids() {
+        let euui = sample(20);
+        let guids = euui.to_braced_guids();
+        assert_eq!(Euui::from_braced_guids(guids.each_ref().map(String::as_str)), Ok(euui));
+
+        let lower = guids[0].to_lowercase();
+        let bare = &guids[1][1..37];
+        assert_eq!(Euui::from_braced_guids([&lower, bare, &guids[2], &guids[3]]), Ok(euui));
+
+        let zero = "00000000-0000-0000-0000-000000000000";
+        assert_eq!(Euui::from_braced_guids([zero; 4]), Ok(Euui::zero()));
+        assert_eq!(
+            Euui::from_braced_guids([zero, zero, "{00000000-0000-0000-0000-000000000000", zero]),
+            Err(ParseEuuiError::InvalidLength(37))
+        );
+        assert_eq!(
+            Euui::from_braced_guids([zero, "{00000000_0000-0000-0000-000000000000}", zero, zero]),
+            Err(ParseEuuiError::InvalidDigit { position: 9, found: '_' })
+        );
+        assert_eq!(
+            Euui::from_braced_guids(["00000000-0000-0000-0000-00000000000g", zero, zero, zero]),
+            Err(ParseEuuiError::InvalidDigit { position: 35, found: 'g' })
+        );
+    }
+
+    #[test]
+    fn test_to_hex_words() {
+        let euui = sample(21);
+        assert_eq!(euui.to_hex_words().concat(), euui.to_string());
+        assert_eq!(Euui::new(0xab, 0, 0, 0).to_hex_words()[0], format!("{}ab", "0".repeat(30)));
+        assert!(euui.to_hex_words().iter().all(|word| word.len() == 32));
+    }
+
+    #[test]
+    fn test_from_hex_words() {
+        let euui = sample(22);
+        let words = euui.to_hex_words();
+        assert_eq!(Euui::from_hex_words(words.each_ref().map(String::as_str)), Ok(euui));
+        let upper = words.each_ref().map(|word| word.to_uppercase());
+        assert_eq!(Euui::from_hex_words(upper.each_ref().map(String::as_str)), Ok(euui));
+
+        let zero = "0".repeat(32);
+        assert_eq!(Euui::from_hex_words([&zero, &zero, &zero, "1"]), Err(ParseEuuiError::InvalidLength(1)));
+        assert_eq!(
+            Euui::from_hex_words([&zero, &format!("0{}", zero), &zero, &zero]),
+            Err(ParseEuuiError::InvalidLength(33))
+        );
+        assert_eq!(
+            Euui::from_hex_words([&zero, &zero, &format!("{}x", &zero[1..]), &zero]),
+            Err(ParseEuuiError::InvalidDigit { position: 31, found: 'x' })
+        );
+    }
+
+    #[test]
+    fn test_ulid_pair() {
+        let euui = sample(23);
+        let string = euui.to_ulid_pair();
+        assert_eq!(string.len(), 107);
+        let ulids: Vec<&str> = string.split('-').collect();
+        assert_eq!(ulids.len(), 4);
+        assert!(ulids.iter().all(|ulid| ulid.len() == 26));
+        assert_eq!(Euui::from_ulid_pair(&string), Ok(euui));
+
+        let euui = Euui::new(0, u128::MAX, 10, 0);
+        assert_eq!(
+            euui.to_ulid_pair(),
+            format!("{}-7{}-{}A-{}", "0".repeat(26), "Z".repeat(25), "0".repeat(25), "0".repeat(26))
+        );
+        assert_eq!(
+            Euui::from_ulid_pair(&format!("8{}", &euui.to_ulid_pair()[1..])),
+            Err(ParseEuuiError::Overflow)
+        );
+        let mut wrong = euui.to_ulid_pair();
+        wrong.replace_range(60..61, "U");
+        assert_eq!(
+            Euui::from_ulid_pair(&wrong),
+            Err(ParseEuuiError::InvalidDigit { position: 60, found: 'U' })
+        );
+        wrong.replace_range(53..54, "+");
+        assert_eq!(
+            Euui::from_ulid_pair(&wrong),
+            Err(ParseEuuiError::InvalidDigit { position: 53, found: '+' })
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+
+/// Error returned when a slice does not hold the number of elements an EUUI needs.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct LengthError {
+    /// The number of elements required.
+    pub expected: usize,
+    /// The number of elements actually provided.
+    pub found: usize,
+}
+
+/// Error returned when a string cannot be parsed as an EUUI.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ParseEuuiError {
+    /// The input does not hold the expected number of characters (the actual count is given).
+    InvalidLength(usize),
+    /// The input holds a character which is not a valid digit.
+    InvalidDigit {
+        /// The index of the offending character (counted in characters, not bytes).
+        position: usize,
+        /// The offending character.
+        found: char,
+    },
+    /// The input holds a numeric digit which is not lower than the base.
+    DigitOutOfRange {
+        /// The index of the offending digit.
+        position: usize,
+        /// The offending digit.
+        digit: u8,
+    },
+    /// The input represents a value which does not fit in 512 bits.
+    Overflow,
+    /// The checksum carried by the input does not match its content.
+    ChecksumMismatch,
+}
+
+/// Error returned when an array of word indices is not a permutation of `0..4` (the rejected array is given).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InvalidPermutation(pub [usize; 4]);
+
+/// Error returned when no EUUI avoiding a set of existing ones was drawn within the allowed number of attempts.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CollisionLimitExceeded {
+    /// The number of EUUIs drawn, all of them colliding.
+    pub attempts: usize,
+}
+
+impl Display for ParseEuuiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseEuuiError::InvalidLength(length) => write!(f, "invalid length : {} characters", length),
+            ParseEuuiError::InvalidDigit { position, found } => {
+                write!(f, "invalid digit {:?} at position {}", found, position)
+            }
+            ParseEuuiError::DigitOutOfRange { position, digit } => {
+                write!(f, "digit {} out of range at position {}", digit, position)
+            }
+            ParseEuuiError::Overflow => write!(f, "value does not fit in 512 bits"),
+            ParseEuuiError::ChecksumMismatch => write!(f, "checksum mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for ParseEuuiError {}
+
+impl Display for LengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid length : expected {} elements, found {}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for LengthError {}
+
+impl Display for InvalidPermutation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a permutation of the 4 word indices", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPermutation {}
+
+impl Display for CollisionLimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "collision limit exceeded : {} colliding attempts", self.attempts)
+    }
+}
+
+impl std::error::Error for CollisionLimitExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CollisionLimitExceeded, Euui, InvalidPermutation, LengthError, ParseEuuiError};
+    use std::error::Error;
+
+    fn parse_then_check(s: &str) -> Result<Euui, Box<dyn Error>> {
+        let euui: Euui = s.parse()?;
+        Euui::try_from_u128_slice(&euui.to_be_guids()[..2])?;
+        Ok(euui)
+    }
+
+    #[test]
+    fn test_boxed_errors() {
+        let errors: Vec<Box<dyn Error>> = vec![
+            Box::new(ParseEuuiError::Overflow),
+            Box::new(LengthError { expected: 4, found: 2 }),
+            Box::new(InvalidPermutation([0, 0, 1, 2])),
+            Box::new(CollisionLimitExceeded { attempts: 3 }),
+        ];
+        assert_eq!(errors[0].to_string(), "value does not fit in 512 bits");
+        assert_eq!(errors[1].to_string(), "invalid length : expected 4 elements, found 2");
+        assert_eq!(errors[2].to_string(), "[0, 0, 1, 2] is not a permutation of the 4 word indices");
+        assert_eq!(errors[3].to_string(), "collision limit exceeded : 3 colliding attempts");
+
+        let error = parse_then_check("0").unwrap_err();
+        assert_eq!(error.downcast_ref::<ParseEuuiError>(), Some(&ParseEuuiError::InvalidLength(1)));
+        let error = parse_then_check(&"0".repeat(128)).unwrap_err();
+        assert_eq!(error.downcast_ref::<LengthError>(), Some(&LengthError { expected: 4, found: 2 }));
+    }
+}
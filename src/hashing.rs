@@ -0,0 +1,111 @@
+use crate::Euui;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha512};
+
+impl Euui {
+    /// Returns the next EUUI of a hash chain : the SHA-512 of the 64 bytes of [Euui::to_be_bytes].
+    ///
+    /// The chain is deterministic and forward-only : anyone can verify that an EUUI follows another one,
+    /// but the previous EUUI cannot be computed back.
+    pub fn hash_next(&self) -> Self {
+        Self::from_be_bytes(Sha512::digest(self.to_be_bytes()).into())
+    }
+
+    /// Combines the entropy of two EUUIs : the SHA-512 of the bytes of `self` followed by the bytes of `other`.
+    ///
+    /// Unlike a XOR, the result stays unpredictable even if both sources are correlated
+    /// (e.g. `a.merge_entropy(&a)` is not zero).
+    pub fn merge_entropy(&self, other: &Euui) -> Self {
+        let digest = Sha512::new()
+            .chain_update(self.to_be_bytes())
+            .chain_update(other.to_be_bytes())
+            .finalize();
+        Self::from_be_bytes(digest.into())
+    }
+
+    /// Returns an order-dependent digest of `ids` : the SHA-512 of the concatenation of their 64 bytes.
+    ///
+    /// See [Euui::xor_all] for an order-independent aggregate.
+    pub fn hash_all(ids: &[Euui]) -> Self {
+        let digest = ids.iter().fold(Sha512::new(), |hasher, id| hasher.chain_update(id.to_be_bytes())).finalize();
+        Self::from_be_bytes(digest.into())
+    }
+
+    /// Derives a reproducible table of `count` EUUIs from a `master` EUUI.
+    ///
+    /// Entry `i` is the HMAC-SHA512 of `i` (as a big-endian u64) keyed with the 64 bytes of `master`,
+    /// so any entry can be recomputed on its own, and entries cannot be guessed without `master`.
+    pub fn derive_table(master: &Euui, count: usize) -> Vec<Euui> {
+        let key = master.to_be_bytes();
+        (0..count as u64)
+            .map(|i| {
+                let mut mac = Hmac::<Sha512>::new_from_slice(&key).expect("HMAC accepts keys of any size");
+                mac.update(&i.to_be_bytes());
+                Self::from_be_bytes(mac.finalize().into_bytes().into())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_merge_entropy() {
+        let (a, b) = (Euui::zero(), Euui::MAX);
+        let merged = a.merge_entropy(&b);
+        assert_eq!(
+            merged.to_string(),
+            "d04a696838c91ec2226cf3a39cdadb48e3bb010ece368b0f81f573a73c2fe70ffd358ceba267e0dc15a73ee0a582972ef3460973ec2384163e486ed97d1095ad"
+        );
+        assert_eq!(a.merge_entropy(&b), merged);
+        assert_ne!(merged, a);
+        assert_ne!(merged, b);
+        assert_ne!(b.merge_entropy(&a), merged);
+        assert!(!a.merge_entropy(&a).is_zero());
+    }
+
+    #[test]
+    fn test_hash_all() {
+        let (a, b) = (Euui::zero(), Euui::MAX);
+        assert_eq!(Euui::hash_all(&[a, b]), a.merge_entropy(&b));
+        assert_ne!(Euui::hash_all(&[b, a]), Euui::hash_all(&[a, b]));
+        assert_eq!(Euui::hash_all(&[a]), a.hash_next());
+        assert_eq!(
+            Euui::hash_all(&[]).to_string(),
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+    }
+
+    #[test]
+    fn test_derive_table() {
+        let master = Euui::zero();
+        let table = Euui::derive_table(&master, 100);
+        assert_eq!(table.len(), 100);
+        assert_eq!(
+            table[0].to_string(),
+            "6a410b5a992a613a3e791dc3368cdd618c8d6e4740bc7f187a45e7b283b732ffef5bd56c9979e4e1bb42f76de87688a48d629fbbe8e703ac8199532547f0267e"
+        );
+        assert_eq!(Euui::derive_table(&master, 100), table);
+        assert_eq!(Euui::derive_table(&master, 10), table[..10]);
+        assert_eq!(table.iter().collect::<HashSet<_>>().len(), 100);
+        assert_ne!(Euui::derive_table(&Euui::MAX, 1)[0], table[0]);
+    }
+
+    #[test]
+    fn test_hash_next() {
+        let zero = Euui::default();
+        assert_eq!(
+            zero.hash_next().to_string(),
+            "7be9fda48f4179e611c698a73cff09faf72869431efee6eaad14de0cb44bbf66503f752b7a8eb17083355f3ce6eb7d2806f236b25af96a24e22b887405c20081"
+        );
+        assert_ne!(zero.hash_next(), zero);
+
+        let chain: Vec<Euui> = std::iter::successors(Some(zero), |euui| Some(euui.hash_next())).take(5).collect();
+        let again: Vec<Euui> = std::iter::successors(Some(zero), |euui| Some(euui.hash_next())).take(5).collect();
+        assert_eq!(chain, again);
+        assert!(chain.windows(2).all(|pair| pair[0].hash_next() == pair[1] && pair[0] != pair[1]));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Euui;
+use heapless::String;
+
+impl Euui {
+    /// Returns the raw hexadecimal string of this EUUI (the same as `.to_string()`) without allocating.
+    pub fn to_heapless_string(&self) -> String<128> {
+        let mut string = String::new();
+        self.write_hex(&mut string).expect("An EUUI is 128 characters wide");
+        string
+    }
+
+    /// Returns the hexadecimal formatted EUUI (the same as [Euui::format]) without allocating.
+    pub fn format_heapless(&self) -> String<131> {
+        let mut string = String::new();
+        self.write_format(&mut string).expect("A formatted EUUI is 131 characters wide");
+        string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::sample;
+
+    #[test]
+    fn test_heapless() {
+        let euui = sample(1);
+        assert_eq!(euui.to_heapless_string().as_str(), euui.to_string());
+        assert_eq!(euui.format_heapless().as_str(), euui.format());
+    }
+}
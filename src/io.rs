@@ -0,0 +1,57 @@
+use crate::Euui;
+use std::io::{Error, ErrorKind, Read, Result};
+
+impl Euui {
+    /// Reads concatenated 64-bytes big-endian EUUIs from `r` until EOF.
+    ///
+    /// Returns an [ErrorKind::UnexpectedEof] error if the stream length is not a multiple of 64.
+    pub fn read_all<R: Read>(r: &mut R) -> Result<Vec<Euui>> {
+        let mut ids = Vec::new();
+        let mut buffer = [0u8; 64];
+        let mut filled = 0;
+        loop {
+            match r.read(&mut buffer[filled..]) {
+                Ok(0) if filled == 0 => return Ok(ids),
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        format!("truncated EUUI : {} bytes out of 64", filled),
+                    ))
+                }
+                Ok(n) => {
+                    filled += n;
+                    if filled == 64 {
+                        ids.push(Euui::from_be_bytes(buffer));
+                        filled = 0;
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn test_read_all() {
+        let ids = [sample(1), sample(2), sample(3)];
+        let bytes: Vec<u8> = ids.iter().flat_map(|id| id.to_be_bytes()).collect();
+        let read = Euui::read_all(&mut Cursor::new(bytes)).unwrap();
+        assert_eq!(read, ids);
+        assert!(Euui::read_all(&mut Cursor::new(Vec::new())).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_all_truncated() {
+        let mut bytes: Vec<u8> = sample(4).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&[1, 2, 3]);
+        let error = Euui::read_all(&mut Cursor::new(bytes)).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    }
+}
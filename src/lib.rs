@@ -7,8 +7,10 @@
 //! A formatted hexadecimal string representing an EUUI is 131 characters wide (two "-" and one "\n").
 //!
 //! You can create :
-//!  - a zero Euui with [Euui::default],
-//!  - or, a random one with [Euui::random].
+//!  - a zero Euui with [Euui::default] or [Euui::zero],
+//!  - or, a random one with [Euui::random] (`random` feature),
+//!  - or, a random one drawn from your own RNG with [Euui::random_with] (`random` feature),
+//!  - or, parse one from its raw hexadecimal string with `.parse()`.
 //!
 //! Then, use :
 //!  - [Euui::format] to display it as 4 u128s or `.to_string()` to get the whole hexadecimal string,
@@ -31,8 +33,39 @@
 //! ```
 //!
 
-use std::fmt::{Display, Formatter};
-use rand::random;
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+mod arithmetic;
+#[cfg(feature = "base58")]
+mod base58;
+#[cfg(feature = "ethnum")]
+mod bignum;
+mod bits;
+mod checksum;
+mod encoding;
+mod error;
+#[cfg(feature = "hashing")]
+mod hashing;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod io;
+#[cfg(feature = "num-traits")]
+mod numeric;
+mod ops;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "uuid")]
+mod uuids;
+#[cfg(feature = "random")]
+mod random;
+
+pub use encoding::{HexCase, HexParseOptions};
+pub use error::{CollisionLimitExceeded, InvalidPermutation, LengthError, ParseEuuiError};
+#[cfg(feature = "random")]
+pub use random::XorShiftRng;
+
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::str::FromStr;
 
 /// Extended Universal Unique Identifier
 ///
@@ -45,19 +78,130 @@ use rand::random;
 /// ```
 ///
 /// The LF character (ASCII 0x0A) is used for new lines.
-#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+///
+/// EUUIs are ordered as 512-bits big-endian numbers. This order is the same as the lexicographic order
+/// of [Euui::to_be_bytes], so these bytes can be used as sortable keys (e.g. in a database).
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Euui([u128; 4]);
 
 impl Euui {
-    /// Provides a new random 512-bits EUUI.
-    pub fn random() -> Self {
-        Self([random(), random(), random(), random()])
+    /// The EUUI with all bits set, which is the greatest EUUI.
+    pub const MAX: Euui = Euui([u128::MAX; 4]);
+
+    /// The size of an EUUI in bits.
+    pub const BITS: u32 = 512;
+
+    /// The size of an EUUI in bytes (as in [Euui::to_be_bytes]).
+    pub const BYTE_LEN: usize = 64;
+
+    /// The length of the raw hexadecimal string of an EUUI (as in the [Display] output).
+    pub const HEX_LEN: usize = 128;
+
+    /// The length of the formatted string of an EUUI (as in [Euui::format]).
+    pub const FORMATTED_LEN: usize = 131;
+
+    /// The marker stored in the last byte (index 63) of the tagged EUUIs (see [Euui::has_tag]).
+    pub const TAG: u8 = 0xe5;
+
+    /// Provides the zero EUUI (all bits unset), which is the smallest EUUI. Same as [Euui::default].
+    pub const fn zero() -> Self {
+        Self([0; 4])
     }
+
+    /// Returns `true` if all bits of this EUUI are unset.
+    pub const fn is_zero(&self) -> bool {
+        self.0[0] == 0 && self.0[1] == 0 && self.0[2] == 0 && self.0[3] == 0
+    }
+
     /// Provides a 512-bits EUUI from 4 GUIDs.
     pub fn new(a: u128, b: u128, c: u128, d: u128) -> Self {
         Self([a, b, c, d])
     }
 
+    /// Provides a 512-bits EUUI holding a u128 value (in #4, the other u128s being zero).
+    pub const fn from_u128(value: u128) -> Self {
+        Self([0, 0, 0, value])
+    }
+
+    /// Provides a 512-bits EUUI from 4 big-endian GUIDs.
+    pub const fn from_be_guids(guids: [u128; 4]) -> Self {
+        Self(guids)
+    }
+
+    /// Provides a 512-bits EUUI from its 4 words, `w0` being the most significant one.
+    ///
+    /// Same as [Euui::new], but usable in const contexts.
+    pub const fn from_words(w0: u128, w1: u128, w2: u128, w3: u128) -> Self {
+        Self([w0, w1, w2, w3])
+    }
+
+    /// Provides a 512-bits EUUI from 8 big-endian u64s.
+    pub const fn from_be_longs(longs: [u64; 8]) -> Self {
+        let mut guids = [0u128; 4];
+        let mut i = 0;
+        while i < 4 {
+            guids[i] = (longs[i * 2] as u128) << 64 | longs[i * 2 + 1] as u128;
+            i += 1;
+        }
+        Self(guids)
+    }
+
+    /// Provides a 512-bits EUUI from 4 `(high, low)` u64 pairs, one pair per u128.
+    pub const fn from_u64_pairs(pairs: [(u64, u64); 4]) -> Self {
+        let mut guids = [0u128; 4];
+        let mut i = 0;
+        while i < 4 {
+            guids[i] = (pairs[i].0 as u128) << 64 | pairs[i].1 as u128;
+            i += 1;
+        }
+        Self(guids)
+    }
+
+    /// Provides a 512-bits EUUI from 64 big-endian u8s.
+    ///
+    /// This can be used in `const` contexts.
+    pub const fn from_be_bytes_const(bytes: [u8; 64]) -> Self {
+        let mut guids = [0u128; 4];
+        let mut i = 0;
+        while i < 64 {
+            guids[i / 16] = guids[i / 16] << 8 | bytes[i] as u128;
+            i += 1;
+        }
+        Self(guids)
+    }
+
+    /// Provides a 512-bits EUUI from 64 big-endian u8s.
+    pub fn from_be_bytes(bytes: [u8; 64]) -> Self {
+        Self::from_be_bytes_const(bytes)
+    }
+
+    /// Provides a 512-bits EUUI from a slice of u64s.
+    ///
+    /// Returns a [LengthError] if the slice does not hold exactly 8 u64s.
+    pub fn try_from_u64_slice(longs: &[u64]) -> Result<Self, LengthError> {
+        match longs.try_into() {
+            Ok(longs) => Ok(Self::from_be_longs(longs)),
+            Err(_) => Err(LengthError { expected: 8, found: longs.len() }),
+        }
+    }
+
+    /// Provides a 512-bits EUUI from a slice of u128s.
+    ///
+    /// Returns a [LengthError] if the slice does not hold exactly 4 u128s.
+    pub fn try_from_u128_slice(guids: &[u128]) -> Result<Self, LengthError> {
+        match guids.try_into() {
+            Ok(guids) => Ok(Self::from_be_guids(guids)),
+            Err(_) => Err(LengthError { expected: 4, found: guids.len() }),
+        }
+    }
+
+    /// Provides a 512-bits EUUI from a slice of big-endian GUIDs, the slice counterpart of [Euui::from_be_guids].
+    ///
+    /// Same as [Euui::try_from_u128_slice].
+    pub fn try_from_be_guids_slice(guids: &[u128]) -> Result<Self, LengthError> {
+        Self::try_from_u128_slice(guids)
+    }
+
     /// Gets one of the 4 u128s composing this EUUI.
     ///
     /// Returns [None] if index >= 4.
@@ -65,6 +209,18 @@ impl Euui {
         if index >= self.0.len() { None } else { Some(self.0[index]) }
     }
 
+    /// Gets one of the 4 u128s composing this EUUI, with its bits reinterpreted as a signed i128.
+    ///
+    /// Returns [None] if index >= 4.
+    pub fn word_as_i128(&self, index: usize) -> Option<i128> {
+        self.u128(index).map(|word| word as i128)
+    }
+
+    /// Provides a 512-bits EUUI from 4 i128s, with their bits reinterpreted as u128s.
+    pub const fn from_i128_words(words: [i128; 4]) -> Self {
+        Self([words[0] as u128, words[1] as u128, words[2] as u128, words[3] as u128])
+    }
+
     /// Gets one of the 64 u8s composing this EUUI.
     ///
     /// Returns [None] if index >= 64.
@@ -78,19 +234,94 @@ impl Euui {
         }
     }
 
+    /// Returns a new EUUI where the u8 at `index` is replaced by `value`.
+    ///
+    /// Returns [None] if index >= 64.
+    pub fn with_byte(&self, index: usize, value: u8) -> Option<Self> {
+        if index >= 64 {
+            return None;
+        }
+        let mut bytes = self.to_be_bytes();
+        bytes[index] = value;
+        Some(Self::from_be_bytes(bytes))
+    }
+
+    /// Returns true if the last byte (index 63) of this EUUI is the [Euui::TAG] marker,
+    /// as in the EUUIs provided by `Euui::random_tagged`.
+    ///
+    /// This is only a heuristic : 1 in 256 untagged random EUUIs also carries the marker.
+    pub fn has_tag(&self) -> bool {
+        self.0[3] as u8 == Self::TAG
+    }
+
     /// Returns the 64 u8s composing this EUUI.
     pub fn to_be_bytes(&self) -> [u8; 64] {
         let mut bytes = [0u8; 64];
-        for i in 0..64 {
-            bytes[i] = self.u8(i).unwrap();
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = self.u8(i).unwrap();
+        }
+        bytes
+    }
+
+    /// Returns the memory representation of this EUUI, as a 512-bits integer, in the native byte order of the target.
+    ///
+    /// On big-endian targets this is [Euui::to_be_bytes], on little-endian targets the same bytes are reversed.
+    pub fn to_ne_bytes(&self) -> [u8; 64] {
+        let mut bytes = self.to_be_bytes();
+        if cfg!(target_endian = "little") {
+            bytes.reverse();
         }
         bytes
     }
 
+    /// Provides a 512-bits EUUI from its memory representation in the native byte order of the target
+    /// (as in [Euui::to_ne_bytes]).
+    pub fn from_ne_bytes(mut bytes: [u8; 64]) -> Self {
+        if cfg!(target_endian = "little") {
+            bytes.reverse();
+        }
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Returns the 64 u8s composing this EUUI (as in [Euui::to_be_bytes]) in a vector.
+    pub fn to_be_bytes_vec(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    /// Returns the 64 u8s composing this EUUI (as in [Euui::to_be_bytes]) in a heap-allocated box.
+    ///
+    /// The bytes are written directly into the box.
+    pub fn to_be_bytes_boxed(&self) -> Box<[u8; 64]> {
+        let mut bytes = Box::new([0u8; 64]);
+        for (chunk, guid) in bytes.chunks_exact_mut(16).zip(self.0) {
+            chunk.copy_from_slice(&guid.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the 64 u8s composing this EUUI (as in [Euui::to_be_bytes]) split into `M` blocks of `N` bytes,
+    /// e.g. `to_chunks::<16, 4>()` returns four 16-bytes blocks.
+    ///
+    /// `N * M` must be 64, which is checked at compile time.
+    pub fn to_chunks<const N: usize, const M: usize>(&self) -> [[u8; N]; M] {
+        const { assert!(N * M == 64, "The chunks must cover exactly the 64 bytes") };
+        let bytes = self.to_be_bytes();
+        std::array::from_fn(|i| bytes[i * N..(i + 1) * N].try_into().expect("N bytes"))
+    }
+
     /// Returns the 4 u128s composing this EUUI.
     ///
     pub fn to_be_guids(&self) -> [u128; 4] { self.0 }
 
+    /// Returns a reference to the 4 u128s composing this EUUI.
+    pub fn as_guids(&self) -> &[u128; 4] { &self.0 }
+
+    /// Returns a mutable reference to the 4 u128s composing this EUUI, to modify the words in place.
+    ///
+    /// The word 0 is the most significant one, as in [Euui::to_be_guids] : the mutations must follow this order
+    /// to keep the byte representation and the ordering of the EUUI meaningful.
+    pub fn as_guids_mut(&mut self) -> &mut [u128; 4] { &mut self.0 }
+
     /// Gets one of the 8 u64s composing this EUUI.
     ///
     /// Returns [None] if index >= 8.
@@ -102,6 +333,183 @@ impl Euui {
         }
     }
 
+    /// Returns an iterator over the 4 u128s composing this EUUI, in big-endian order.
+    pub fn u128_lanes(&self) -> impl Iterator<Item = u128> {
+        self.0.into_iter()
+    }
+
+    /// Returns an iterator over the 8 u64s composing this EUUI, in big-endian order.
+    pub fn u64_lanes(&self) -> impl Iterator<Item = u64> {
+        self.to_be_longs().into_iter()
+    }
+
+    /// Returns an iterator over the 64 u8s composing this EUUI, in big-endian order.
+    pub fn u8_lanes(&self) -> impl Iterator<Item = u8> {
+        self.to_be_bytes().into_iter()
+    }
+
+    /// Returns the 4 `(high, low)` u64 pairs composing this EUUI, one pair per u128.
+    pub fn to_u64_pairs(&self) -> [(u64, u64); 4] {
+        self.0.map(|guid| ((guid >> 64) as u64, guid as u64))
+    }
+
+    /// Returns the counter of a Snowflake-like EUUI (the first u64).
+    ///
+    /// See [Euui::new_snowflake].
+    pub fn snowflake_counter(&self) -> u64 {
+        (self.0[0] >> 64) as u64
+    }
+
+    /// Returns the node id of a Snowflake-like EUUI (the second u64).
+    ///
+    /// See [Euui::new_snowflake].
+    pub fn snowflake_node(&self) -> u64 {
+        self.0[0] as u64
+    }
+
+    /// Returns the 48-bits Unix timestamp (in milliseconds) of an EUUI provided by `Euui::from_time_node_seq`
+    /// (the 48 most significant bits).
+    pub fn time_node_seq_timestamp_millis(&self) -> u64 {
+        (self.0[0] >> 80) as u64
+    }
+
+    /// Returns the node id of an EUUI provided by `Euui::from_time_node_seq` (the 32 bits after the timestamp).
+    ///
+    /// This layout differs from the Snowflake-like one : it is not the same value as [Euui::snowflake_node].
+    pub fn time_node_seq_node_id(&self) -> u32 {
+        (self.0[0] >> 48) as u32
+    }
+
+    /// Returns the sequence number of an EUUI provided by `Euui::from_time_node_seq` (the 32 bits after the node id).
+    pub fn time_node_seq_sequence(&self) -> u32 {
+        (self.0[0] >> 16) as u32
+    }
+
+    /// Returns the 8 u64s composing this EUUI.
+    pub fn to_be_longs(&self) -> [u64; 8] {
+        let mut longs = [0u64; 8];
+        for (i, long) in longs.iter_mut().enumerate() {
+            *long = self.u64(i).unwrap();
+        }
+        longs
+    }
+
+    /// Returns a new EUUI whose words are `f(index, word)` for each of the 4 u128s of this EUUI.
+    pub fn map_words<F: Fn(usize, u128) -> u128>(&self, f: F) -> Self {
+        let mut guids = self.0;
+        for (i, guid) in guids.iter_mut().enumerate() {
+            *guid = f(i, *guid);
+        }
+        Self(guids)
+    }
+
+    /// Returns a new EUUI where each `Some` of `words` replaces the matching u128, `None` keeping the current one.
+    pub fn with_words(&self, words: [Option<u128>; 4]) -> Self {
+        self.map_words(|i, word| words[i].unwrap_or(word))
+    }
+
+    /// Compares the EUUIs of `a` and `b` pairwise, up to the length of the shortest slice.
+    ///
+    /// With the `simd` feature, each comparison is done on portable SIMD vectors built from the words
+    /// (see `benches/simd.rs` to compare it with the scalar comparison on your target).
+    pub fn eq_batch(a: &[Euui], b: &[Euui]) -> Vec<bool> {
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| {
+                #[cfg(feature = "simd")]
+                return simd::eq(a, b);
+                #[cfg(not(feature = "simd"))]
+                return a == b;
+            })
+            .collect()
+    }
+
+    /// Returns a new EUUI whose word `i` is the word `order[i]` of this EUUI.
+    ///
+    /// Returns an [InvalidPermutation] if `order` is not a permutation of `0..4`.
+    pub fn permute_words(&self, order: [usize; 4]) -> Result<Self, InvalidPermutation> {
+        let mut seen = [false; 4];
+        for index in order {
+            if index >= 4 || seen[index] {
+                return Err(InvalidPermutation(order));
+            }
+            seen[index] = true;
+        }
+        Ok(self.map_words(|i, _| self.0[order[i]]))
+    }
+
+    /// Returns a new EUUI with the words `i` and `j` of this EUUI swapped.
+    ///
+    /// Returns [None] if `i >= 4` or `j >= 4`.
+    pub fn swap_words(&self, i: usize, j: usize) -> Option<Self> {
+        if i >= 4 || j >= 4 {
+            return None;
+        }
+        let mut guids = self.0;
+        guids.swap(i, j);
+        Some(Self(guids))
+    }
+
+    /// Returns a stable 32-bits hash of this EUUI.
+    ///
+    /// The algorithm is FNV-1a (32 bits) over the 64 big-endian bytes of [Euui::to_be_bytes].
+    /// Unlike the [Hash](std::hash::Hash) implementation, this value does not depend on the platform nor on the crate version,
+    /// so it can be persisted.
+    pub fn hash32(&self) -> u32 {
+        self.to_be_bytes()
+            .iter()
+            .fold(0x811c9dc5u32, |hash, byte| (hash ^ *byte as u32).wrapping_mul(0x01000193))
+    }
+
+    /// Returns a stable 64-bits hash of this EUUI.
+    ///
+    /// The algorithm is FNV-1a (64 bits) over the 64 big-endian bytes of [Euui::to_be_bytes].
+    /// Unlike the [Hash](std::hash::Hash) implementation, this value does not depend on the platform nor on the crate version,
+    /// so it can be persisted.
+    pub fn hash64(&self) -> u64 {
+        self.to_be_bytes()
+            .iter()
+            .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+    }
+
+    /// Returns a stable 128-bits digest of this EUUI, e.g. for cache keys.
+    ///
+    /// Given `K = 0x9e3779b97f4a7c15f39cc0605cedc835` and `mix(x)` applying twice `x ^= x >> 64; x *= K`
+    /// (wrapping) then a final `x ^= x >> 64`, the digest starts at `h = K` and each u128 `w` from #1 to #4
+    /// is folded with `h = mix(h ^ w)`. Unlike a plain XOR of the words, the result depends on word order
+    /// and every input bit affects every output bit.
+    pub fn short_id(&self) -> u128 {
+        const K: u128 = 0x9e3779b97f4a7c15f39cc0605cedc835;
+        let mix = |mut x: u128| {
+            x ^= x >> 64;
+            x = x.wrapping_mul(K);
+            x ^= x >> 64;
+            x = x.wrapping_mul(K);
+            x ^ x >> 64
+        };
+        self.0.iter().fold(K, |hash, word| mix(hash ^ word))
+    }
+
+    /// Returns a 16-bits fingerprint of each u128 composing this EUUI, e.g. to spot in logs which words differ
+    /// between two EUUIs.
+    ///
+    /// The algorithm is CRC-16/CCITT-FALSE over the 16 big-endian bytes of each word.
+    pub fn word_fingerprints(&self) -> [u16; 4] {
+        self.0.map(|guid| checksum::crc16(&guid.to_be_bytes()))
+    }
+
+    /// Returns which u128s differ between this EUUI and `other` : the item `i` is true if the words `i` differ.
+    pub fn word_diff(&self, other: &Euui) -> [bool; 4] {
+        [0, 1, 2, 3].map(|i| self.0[i] != other.0[i])
+    }
+
+    /// Merges two EUUIs bit by bit : each bit is taken from `a` where `mask` is set, and from `b` otherwise.
+    ///
+    /// This computes `(a & mask) | (b & !mask)`.
+    pub fn select(mask: &Euui, a: &Euui, b: &Euui) -> Self {
+        a.map_words(|i, word| (word & mask.0[i]) | (b.0[i] & !mask.0[i]))
+    }
+
     /// Returns a hexadecimal formatted EUUI which follows this pattern (given #x is `self.0[x - 1]`) :
     /// ```txt
     /// #1-#2
@@ -115,26 +523,149 @@ impl Euui {
     /// d43ed7632e94801a395a5454a382dff1-23decf62d51eafee3ec0bb98b1b90d15
     /// ```
     pub fn format(&self) -> String {
-        format!(
-            "{:032x}-{:032x}\n{:032x}-{:032x}",
+        let mut formatted = String::with_capacity(Self::FORMATTED_LEN);
+        self.write_format(&mut formatted).expect("Writing into a String never fails");
+        formatted
+    }
+
+    /// Writes the hexadecimal formatted EUUI (the same as [Euui::format]) into any [Write] target.
+    pub fn write_format<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        self.write_format_with(w, '-', '\n')
+    }
+
+    /// Returns a hexadecimal formatted EUUI like [Euui::format], with custom separators
+    /// between the u128s of a line (`word_sep`) and between the two lines (`line_sep`).
+    ///
+    /// `format_with('-', '\n')` is the same as [Euui::format].
+    pub fn format_with(&self, word_sep: char, line_sep: char) -> String {
+        let mut formatted = String::with_capacity(Self::HEX_LEN + word_sep.len_utf8() * 2 + line_sep.len_utf8());
+        self.write_format_with(&mut formatted, word_sep, line_sep)
+            .expect("Writing into a String never fails");
+        formatted
+    }
+
+    fn write_format_with<W: Write>(&self, w: &mut W, word_sep: char, line_sep: char) -> std::fmt::Result {
+        write!(
+            w,
+            "{:032x}{}{:032x}{}{:032x}{}{:032x}",
+            self.0[0], word_sep, self.0[1], line_sep, self.0[2], word_sep, self.0[3]
+        )
+    }
+
+    /// Returns the hexadecimal string of this EUUI, as a 512-bits unsigned integer, without leading zeros.
+    ///
+    /// The zero EUUI is `"0"`.
+    pub fn to_compact_hex(&self) -> String {
+        let hex = self.to_string();
+        match hex.trim_start_matches('0') {
+            "" => "0".to_string(),
+            compact => compact.to_string(),
+        }
+    }
+
+    /// Writes the raw hexadecimal string of this EUUI (the same as `.to_string()`) into any [Write] target.
+    ///
+    /// This avoids an intermediate allocation when rendering into an existing buffer.
+    pub fn write_hex<W: Write>(&self, w: &mut W) -> std::fmt::Result {
+        write!(
+            w,
+            "{:032x}{:032x}{:032x}{:032x}",
             self.0[0], self.0[1], self.0[2], self.0[3]
         )
     }
 }
 
-impl Display for Euui {
+impl FromStr for Euui {
+    type Err = ParseEuuiError;
+
+    /// Parses a raw hexadecimal string of exactly 128 digits (the [Display] output).
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex_strict(s, HexCase::Any)
+    }
+}
+
+impl TryFrom<&str> for Euui {
+    type Error = ParseEuuiError;
+
+    /// Same as [Euui::from_str].
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<Vec<u8>> for Euui {
+    type Error = LengthError;
+
+    /// Consumes 64 big-endian bytes (as in [Euui::from_be_bytes]).
+    ///
+    /// Returns a [LengthError] if the vector does not hold exactly 64 bytes.
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        let found = value.len();
+        match value.try_into() {
+            Ok(bytes) => Ok(Self::from_be_bytes(bytes)),
+            Err(_) => Err(LengthError { expected: 64, found }),
+        }
+    }
+}
+
+impl Debug for Euui {
+    /// Writes the 4 u128s in hexadecimal : `Euui([0x#1, 0x#2, 0x#3, 0x#4])`.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{:032x}{:032x}{:032x}{:032x}",
+            "Euui([0x{:032x}, 0x{:032x}, 0x{:032x}, 0x{:032x}])",
             self.0[0], self.0[1], self.0[2], self.0[3]
         )
     }
 }
 
+impl Display for Euui {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_hex(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Euui;
+    use crate::{Euui, InvalidPermutation, LengthError};
+    use crate::ParseEuuiError;
+
+    /// Returns the `n`-th EUUI of a fixed sequence of well-mixed EUUIs (SplitMix64), standing in for
+    /// `Euui::random` in the tests which do not test random generation.
+    pub(crate) fn sample(n: u64) -> Euui {
+        const GAMMA: u64 = 0x9e3779b97f4a7c15;
+        let mut state = (n * 8).wrapping_mul(GAMMA);
+        Euui::from_be_longs([(); 8].map(|_| {
+            state = state.wrapping_add(GAMMA);
+            let mut z = state;
+            z = (z ^ z >> 30).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ z >> 27).wrapping_mul(0x94d049bb133111eb);
+            z ^ z >> 31
+        }))
+    }
+
+    #[test]
+    fn test_constants() {
+        let euui = sample(1);
+        assert_eq!(Euui::BITS, 512);
+        assert_eq!(Euui::BYTE_LEN, 64);
+        assert_eq!(Euui::HEX_LEN, 128);
+        assert_eq!(Euui::FORMATTED_LEN, 131);
+        assert_eq!(euui.to_be_bytes().len(), Euui::BYTE_LEN);
+        assert_eq!(euui.to_string().len(), Euui::HEX_LEN);
+        assert_eq!(euui.format().len(), Euui::FORMATTED_LEN);
+    }
+
+    #[test]
+    fn test_from_words() {
+        const EUUI: Euui = Euui::from_words(1, 2, 3, 4);
+        assert_eq!(EUUI, Euui::from_be_guids([1, 2, 3, 4]));
+        let (w0, w1, w2, w3) = (u128::MAX, 0, 42, 1 << 127);
+        assert_eq!(Euui::from_words(w0, w1, w2, w3), Euui::from_be_guids([w0, w1, w2, w3]));
+        assert_eq!(Euui::from_words(w0, w1, w2, w3), Euui::new(w0, w1, w2, w3));
+    }
 
     #[test]
     fn test_zero() {
@@ -145,6 +676,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "random")]
     #[test]
     fn test_non_zero() {
         let euui = Euui::random();
@@ -154,9 +686,411 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "random")]
     #[test]
     fn format_random() {
         let euui = Euui::random();
         println!("{}\n\n{}", euui.format(), euui);
     }
+
+    #[test]
+    fn test_format_with() {
+        let euui = sample(2);
+        assert_eq!(euui.format_with('-', '\n'), euui.format());
+        let guids = euui.to_be_guids();
+        assert_eq!(
+            euui.format_with('|', ';'),
+            format!("{:032x}|{:032x};{:032x}|{:032x}", guids[0], guids[1], guids[2], guids[3])
+        );
+        let formatted = euui.format_with('→', '¶');
+        assert_eq!(formatted.len(), Euui::HEX_LEN + '→'.len_utf8() * 2 + '¶'.len_utf8());
+        assert_eq!(formatted.split('¶').count(), 2);
+    }
+
+    #[test]
+    fn test_to_compact_hex() {
+        assert_eq!(Euui::zero().to_compact_hex(), "0");
+        assert_eq!(Euui::from_u128(0xff).to_compact_hex(), "ff");
+        assert_eq!(Euui::new(0, 0, 1, 0).to_compact_hex(), format!("1{}", "0".repeat(32)));
+        assert_eq!(Euui::MAX.to_compact_hex(), Euui::MAX.to_string());
+        let euui = sample(3).with_byte(0, 0x10).unwrap();
+        assert_eq!(euui.to_compact_hex(), euui.to_string());
+    }
+
+    #[test]
+    fn test_write_hex() {
+        let euui = sample(4);
+        let mut buffer = String::with_capacity(128);
+        euui.write_hex(&mut buffer).unwrap();
+        assert_eq!(buffer, euui.to_string());
+    }
+
+    #[test]
+    fn test_from_be_bytes_const() {
+        const BYTES: [u8; 64] = {
+            let mut bytes = [0u8; 64];
+            let mut i = 0;
+            while i < 64 {
+                bytes[i] = i as u8;
+                i += 1;
+            }
+            bytes
+        };
+        const EUUI: Euui = Euui::from_be_bytes_const(BYTES);
+        assert_eq!(EUUI.to_be_bytes(), BYTES);
+        assert_eq!(EUUI.u128(0), Some(0x000102030405060708090a0b0c0d0e0f));
+        assert_eq!(Euui::from_be_bytes(BYTES), EUUI);
+
+        let euui = sample(5);
+        assert_eq!(Euui::from_be_bytes(euui.to_be_bytes()), euui);
+    }
+
+    #[test]
+    fn test_ne_bytes() {
+        let euui = sample(6);
+        assert_eq!(Euui::from_ne_bytes(euui.to_ne_bytes()), euui);
+        let one = Euui::from_u128(1).to_ne_bytes();
+        assert_eq!(one[0], cfg!(target_endian = "little") as u8);
+        assert_eq!(one[63], cfg!(target_endian = "big") as u8);
+        if cfg!(target_endian = "big") {
+            assert_eq!(euui.to_ne_bytes(), euui.to_be_bytes());
+        } else {
+            assert_ne!(euui.to_ne_bytes(), euui.to_be_bytes());
+        }
+    }
+
+    #[test]
+    fn test_to_be_bytes_vec() {
+        let euui = sample(7);
+        let bytes = euui.to_be_bytes_vec();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes, euui.to_be_bytes());
+        assert_eq!(Euui::try_from(bytes), Ok(euui));
+    }
+
+    #[test]
+    fn test_to_be_bytes_boxed() {
+        let euui = sample(8);
+        let boxed = euui.to_be_bytes_boxed();
+        assert_eq!(*boxed, euui.to_be_bytes());
+        let slices: Vec<Box<[u8]>> = vec![boxed, Euui::MAX.to_be_bytes_boxed()];
+        assert_eq!(Euui::try_from(slices[0].to_vec()), Ok(euui));
+        assert!(slices[1].iter().all(|&byte| byte == 0xff));
+    }
+
+    #[test]
+    fn test_to_chunks() {
+        let euui = sample(9);
+        let bytes = euui.to_be_bytes();
+        let blocks: [[u8; 8]; 8] = euui.to_chunks();
+        assert_eq!(blocks.concat(), bytes);
+        assert_eq!(blocks[1], euui.u64(1).unwrap().to_be_bytes());
+        let blocks = euui.to_chunks::<16, 4>();
+        assert_eq!(blocks, euui.to_be_guids().map(u128::to_be_bytes));
+        let blocks = euui.to_chunks::<32, 2>();
+        assert_eq!(blocks[0], bytes[..32]);
+        assert_eq!(blocks[1], bytes[32..]);
+    }
+
+    #[test]
+    fn test_stable_hashes() {
+        let zero = Euui::default();
+        assert_eq!(zero.hash32(), 0xdfde6ac5);
+        assert_eq!(zero.hash64(), 0xb9b23f3a46fd0825);
+
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let euui = Euui::from_be_bytes(bytes);
+        assert_eq!(euui.hash32(), 0x6d3a0905);
+        assert_eq!(euui.hash64(), 0x8368214f77995ee5);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let euui = sample(10);
+        assert_eq!(euui.to_string().parse::<Euui>(), Ok(euui));
+        assert_eq!(euui.to_string().to_uppercase().parse::<Euui>(), Ok(euui));
+        assert_eq!("00".parse::<Euui>(), Err(ParseEuuiError::InvalidLength(2)));
+        assert_eq!(
+            "g".repeat(128).parse::<Euui>(),
+            Err(ParseEuuiError::InvalidDigit { position: 0, found: 'g' })
+        );
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let mut string = sample(11).to_string();
+        string.replace_range(50..51, "z");
+        let error = string.parse::<Euui>().unwrap_err();
+        assert_eq!(error, ParseEuuiError::InvalidDigit { position: 50, found: 'z' });
+        assert_eq!(error.to_string(), "invalid digit 'z' at position 50");
+        assert_eq!(
+            ParseEuuiError::InvalidLength(127).to_string(),
+            "invalid length : 127 characters"
+        );
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let euui = sample(12);
+        let string = euui.to_string();
+        let parsed: Result<Euui, _> = string.as_str().try_into();
+        assert_eq!(parsed, Ok(euui));
+        let truncated: Result<Euui, _> = string[..127].try_into();
+        assert_eq!(truncated, Err(ParseEuuiError::InvalidLength(127)));
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        let euui = sample(13);
+        let bytes = euui.to_be_bytes().to_vec();
+        assert_eq!(Euui::try_from(bytes.clone()), Ok(euui));
+        assert_eq!(Euui::try_from(bytes[..63].to_vec()), Err(LengthError { expected: 64, found: 63 }));
+        assert_eq!(Euui::try_from(vec![0u8; 65]), Err(LengthError { expected: 64, found: 65 }));
+        assert_eq!(Euui::try_from(Vec::new()), Err(LengthError { expected: 64, found: 0 }));
+    }
+
+    #[test]
+    fn test_ord_matches_be_bytes() {
+        for n in 0..1000 {
+            let a = sample(2 * n);
+            let b = sample(2 * n + 1);
+            assert_eq!(a.cmp(&b), a.to_be_bytes().cmp(&b.to_be_bytes()));
+        }
+        let a = Euui::new(1, 0, 0, 0);
+        let b = Euui::new(0, u128::MAX, u128::MAX, u128::MAX);
+        assert!(a > b);
+        assert_eq!(a.cmp(&b), a.to_be_bytes().cmp(&b.to_be_bytes()));
+    }
+
+    #[test]
+    fn test_map_words() {
+        let euui = sample(16);
+        assert_eq!(euui.map_words(|_, w| w ^ u128::MAX), !euui);
+        assert_eq!(euui.map_words(|_, w| w), euui);
+        assert_eq!(
+            euui.map_words(|i, w| if i == 2 { 0 } else { w }).to_be_guids(),
+            [euui.u128(0).unwrap(), euui.u128(1).unwrap(), 0, euui.u128(3).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_with_byte() {
+        let euui = sample(17);
+        let first = euui.with_byte(0, 0xab).unwrap();
+        assert_eq!(first.u8(0), Some(0xab));
+        assert_eq!(first.to_be_bytes()[1..], euui.to_be_bytes()[1..]);
+        let last = euui.with_byte(63, 0xcd).unwrap();
+        assert_eq!(last.u8(63), Some(0xcd));
+        assert_eq!(last.to_be_bytes()[..63], euui.to_be_bytes()[..63]);
+        assert_eq!(Euui::zero().with_byte(63, 1), Some(Euui::new(0, 0, 0, 1)));
+        assert_eq!(euui.with_byte(64, 0), None);
+    }
+
+    #[test]
+    fn test_with_words() {
+        let euui = sample(18);
+        let updated = euui.with_words([Some(1), None, None, Some(4)]);
+        assert_eq!(
+            updated,
+            Euui::new(1, euui.u128(1).unwrap(), euui.u128(2).unwrap(), 4)
+        );
+        assert_eq!(euui.with_words([None; 4]), euui);
+    }
+
+    #[test]
+    fn test_eq_batch() {
+        let a = [sample(19), sample(20), sample(21)];
+        let b = [a[0], sample(22), a[2], a[1]];
+        assert_eq!(Euui::eq_batch(&a, &b), vec![true, false, true]);
+        assert_eq!(
+            Euui::eq_batch(&a, &b),
+            a.iter().zip(&b).map(|(a, b)| a.0 == b.0).collect::<Vec<_>>()
+        );
+        assert!(Euui::eq_batch(&a, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_permute_words() {
+        let euui = Euui::new(1, 2, 3, 4);
+        assert_eq!(euui.permute_words([0, 1, 2, 3]), Ok(euui));
+        assert_eq!(euui.permute_words([3, 2, 1, 0]), Ok(Euui::new(4, 3, 2, 1)));
+        assert_eq!(
+            euui.permute_words([0, 0, 1, 2]),
+            Err(InvalidPermutation([0, 0, 1, 2]))
+        );
+        assert_eq!(
+            euui.permute_words([0, 1, 2, 4]),
+            Err(InvalidPermutation([0, 1, 2, 4]))
+        );
+    }
+
+    #[test]
+    fn test_swap_words() {
+        let euui = Euui::new(1, 2, 3, 4);
+        assert_eq!(euui.swap_words(0, 3), Some(Euui::new(4, 2, 3, 1)));
+        assert_eq!(euui.swap_words(3, 0), euui.permute_words([3, 1, 2, 0]).ok());
+        assert_eq!(euui.swap_words(2, 2), Some(euui));
+        assert_eq!(euui.swap_words(0, 4), None);
+        assert_eq!(euui.swap_words(4, 1), None);
+    }
+
+    #[test]
+    fn test_as_guids() {
+        let mut euui = Euui::new(1, 2, 3, 4);
+        assert_eq!(euui.as_guids(), &[1, 2, 3, 4]);
+        euui.as_guids_mut()[2] = 42;
+        assert_eq!(euui, Euui::new(1, 2, 42, 4));
+        euui.as_guids_mut().iter_mut().for_each(|guid| *guid += 1);
+        assert_eq!(euui.to_be_guids(), [2, 3, 43, 5]);
+    }
+
+    #[test]
+    fn test_lanes() {
+        let euui = sample(23);
+        assert_eq!(euui.u128_lanes().collect::<Vec<_>>(), euui.to_be_guids());
+        assert_eq!(euui.u64_lanes().collect::<Vec<_>>(), euui.to_be_longs());
+        assert_eq!(euui.u8_lanes().collect::<Vec<_>>(), euui.to_be_bytes());
+    }
+
+    #[test]
+    fn test_u64_pairs() {
+        let euui = sample(24);
+        assert_eq!(Euui::from_u64_pairs(euui.to_u64_pairs()), euui);
+
+        let pairs = [(1, 2), (3, 4), (5, 6), (7, 8)];
+        let euui = Euui::from_u64_pairs(pairs);
+        assert_eq!(euui, Euui::from_be_longs([1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(euui.to_u64_pairs(), pairs);
+        assert_eq!(euui.u128(1), Some(3 << 64 | 4));
+    }
+
+    #[test]
+    fn test_debug_hex() {
+        let euui = sample(25);
+        let debug = format!("{:?}", euui);
+        for i in 0..4 {
+            assert!(debug.contains(&format!("0x{:032x}", euui.u128(i).unwrap())));
+        }
+        assert_eq!(
+            format!("{:?}", Euui::new(1, 2, 3, 0xff)),
+            format!("Euui([0x{0}1, 0x{0}2, 0x{0}3, 0x{1}ff])", "0".repeat(31), "0".repeat(30))
+        );
+    }
+
+    #[test]
+    fn test_i128_words() {
+        let euui = Euui::new(1 << 127 | 5, u128::MAX, 42, 0);
+        assert_eq!(euui.word_as_i128(0), Some(i128::MIN + 5));
+        assert_eq!(euui.word_as_i128(1), Some(-1));
+        assert_eq!(euui.word_as_i128(2), Some(42));
+        assert_eq!(euui.word_as_i128(4), None);
+        assert_eq!(Euui::from_i128_words([i128::MIN + 5, -1, 42, 0]), euui);
+
+        let euui = sample(26);
+        let words = [0, 1, 2, 3].map(|i| euui.word_as_i128(i).unwrap());
+        assert_eq!(Euui::from_i128_words(words), euui);
+    }
+
+    #[test]
+    fn test_short_id() {
+        assert_eq!(Euui::zero().short_id(), 0xc747e43ac253e9a593a31081032d2147);
+        assert_eq!(Euui::new(1, 2, 3, 4).short_id(), 0x6fdd5dc323fee05697c6af26d0485dd8);
+        assert_ne!(Euui::new(1, 2, 3, 4).short_id(), Euui::new(2, 1, 3, 4).short_id());
+        for n in 0..100 {
+            assert_ne!(sample(2 * n).short_id(), sample(2 * n + 1).short_id());
+        }
+    }
+
+    #[test]
+    fn test_word_fingerprints() {
+        assert_eq!(Euui::new(0, 1, 0, 0).word_fingerprints(), [0x6a0a, 0x7a2b, 0x6a0a, 0x6a0a]);
+        let euui = sample(29);
+        let fingerprints = euui.word_fingerprints();
+        for i in 0..4 {
+            let mut words = [None; 4];
+            words[i] = Some(!euui.u128(i).unwrap());
+            let changed = euui.with_words(words).word_fingerprints();
+            let differences: Vec<usize> = (0..4).filter(|j| changed[*j] != fingerprints[*j]).collect();
+            assert_eq!(differences, vec![i]);
+        }
+    }
+
+    #[test]
+    fn test_word_diff() {
+        let euui = sample(30);
+        assert_eq!(euui.word_diff(&euui), [false; 4]);
+        assert_eq!(euui.word_diff(&!euui), [true; 4]);
+        let second = euui.with_words([None, Some(!euui.u128(1).unwrap()), None, None]);
+        assert_eq!(euui.word_diff(&second), [false, true, false, false]);
+        assert_eq!(second.word_diff(&euui), [false, true, false, false]);
+        assert_eq!(Euui::new(1, 2, 3, 4).word_diff(&Euui::new(1, 0, 3, 0)), [false, true, false, true]);
+    }
+
+    #[test]
+    fn test_select() {
+        let a = sample(31);
+        let b = sample(32);
+        let ones = Euui::new(u128::MAX, u128::MAX, u128::MAX, u128::MAX);
+        assert_eq!(Euui::select(&ones, &a, &b), a);
+        assert_eq!(Euui::select(&Euui::default(), &a, &b), b);
+
+        let split = Euui::new(u128::MAX, 0, u128::MAX, 0);
+        assert_eq!(
+            Euui::select(&split, &a, &b),
+            Euui::new(a.u128(0).unwrap(), b.u128(1).unwrap(), a.u128(2).unwrap(), b.u128(3).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_try_from_u64_slice() {
+        let euui = sample(33);
+        let longs = euui.to_be_longs().to_vec();
+        assert_eq!(Euui::try_from_u64_slice(&longs), Ok(euui));
+        assert_eq!(
+            Euui::try_from_u64_slice(&longs[..7]),
+            Err(LengthError { expected: 8, found: 7 })
+        );
+        let mut too_long = longs.clone();
+        too_long.push(0);
+        assert_eq!(
+            Euui::try_from_u64_slice(&too_long),
+            Err(LengthError { expected: 8, found: 9 })
+        );
+    }
+
+    #[test]
+    fn test_try_from_be_guids_slice() {
+        assert_eq!(
+            Euui::try_from_be_guids_slice(&[1, 2, 3, 4]),
+            Ok(Euui::from_be_guids([1, 2, 3, 4]))
+        );
+        assert_eq!(
+            Euui::try_from_be_guids_slice(&[1, 2, 3]),
+            Err(LengthError { expected: 4, found: 3 })
+        );
+        assert_eq!(
+            Euui::try_from_be_guids_slice(&[1, 2, 3, 4, 5]),
+            Err(LengthError { expected: 4, found: 5 })
+        );
+    }
+
+    #[test]
+    fn test_try_from_u128_slice() {
+        let euui = sample(34);
+        let guids = euui.to_be_guids().to_vec();
+        assert_eq!(Euui::try_from_u128_slice(&guids), Ok(euui));
+        assert_eq!(
+            Euui::try_from_u128_slice(&guids[..3]),
+            Err(LengthError { expected: 4, found: 3 })
+        );
+        let mut too_long = guids.clone();
+        too_long.push(0);
+        assert_eq!(
+            Euui::try_from_u128_slice(&too_long),
+            Err(LengthError { expected: 4, found: 5 })
+        );
+    }
 }
\ No newline at end of file
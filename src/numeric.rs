@@ -0,0 +1,45 @@
+use crate::Euui;
+use num_traits::{Bounded, One, Zero};
+
+impl Zero for Euui {
+    fn zero() -> Self {
+        Euui::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        Euui::is_zero(self)
+    }
+}
+
+impl One for Euui {
+    fn one() -> Self {
+        Euui::new(0, 0, 0, 1)
+    }
+}
+
+impl Bounded for Euui {
+    fn min_value() -> Self {
+        Euui::zero()
+    }
+
+    fn max_value() -> Self {
+        Euui::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use num_traits::{Bounded, One, Zero};
+
+    #[test]
+    fn test_num_traits() {
+        assert_eq!(Euui::zero(), <Euui as Zero>::zero());
+        assert!(Zero::is_zero(&Euui::default()));
+        assert!(!Zero::is_zero(&Euui::MAX));
+        assert_eq!(<Euui as One>::one(), Euui::new(0, 0, 0, 1));
+        assert_eq!(<Euui as Bounded>::min_value(), Euui::zero());
+        assert_eq!(<Euui as Bounded>::max_value(), Euui::MAX);
+        assert_eq!(Euui::MAX + <Euui as One>::one(), <Euui as Zero>::zero());
+    }
+}
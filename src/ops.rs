@@ -0,0 +1,95 @@
+use crate::Euui;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Shl, Shr};
+
+impl Not for Euui {
+    type Output = Euui;
+
+    fn not(self) -> Self::Output {
+        self.map_words(|_, word| !word)
+    }
+}
+
+impl BitAnd for Euui {
+    type Output = Euui;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.map_words(|i, word| word & rhs.0[i])
+    }
+}
+
+impl BitOr for Euui {
+    type Output = Euui;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.map_words(|i, word| word | rhs.0[i])
+    }
+}
+
+impl BitXor for Euui {
+    type Output = Euui;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.map_words(|i, word| word ^ rhs.0[i])
+    }
+}
+
+/// Left shift, filling the low bits with zeros. Shifting by 512 bits or more gives zero,
+/// see [Euui::checked_shl] to detect it.
+impl Shl<u32> for Euui {
+    type Output = Euui;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.shl_or_zero(rhs)
+    }
+}
+
+/// Right shift, filling the high bits with zeros. Shifting by 512 bits or more gives zero,
+/// see [Euui::checked_shr] to detect it.
+impl Shr<u32> for Euui {
+    type Output = Euui;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.shr_or_zero(rhs)
+    }
+}
+
+/// Adds two EUUIs as 512-bits unsigned integers.
+///
+/// Unlike primitive integers in debug builds, `+` never panics on overflow : it wraps around at 2^512.
+impl Add for Euui {
+    type Output = Euui;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.wrapping_add(&rhs)
+    }
+}
+
+/// Multiplies two EUUIs as 512-bits unsigned integers.
+///
+/// Unlike primitive integers in debug builds, `*` never panics on overflow : it wraps around at 2^512,
+/// keeping only the 512 low bits of the product.
+impl Mul for Euui {
+    type Output = Euui;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.wrapping_mul(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+
+    #[test]
+    fn test_bitwise_ops() {
+        let a = sample(1);
+        let b = sample(2);
+        assert_eq!(!!a, a);
+        assert_eq!(a & !a, Euui::default());
+        assert_eq!((a | !a).to_be_guids(), [u128::MAX; 4]);
+        assert_eq!(a ^ a, Euui::default());
+        assert_eq!((a ^ b) ^ b, a);
+        assert_eq!(Euui::select(&b, &a, &Euui::default()), a & b);
+    }
+}
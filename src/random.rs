@@ -0,0 +1,520 @@
+use crate::{CollisionLimitExceeded, Euui};
+use rand::{random, Fill, Rng, RngCore};
+use std::collections::HashSet;
+
+/// A fast, **non-cryptographic** xorshift64* random number generator.
+///
+/// It is meant for simulations (e.g. Monte Carlo) which need a lot of EUUIs quickly and reproducibly.
+/// Never use it to generate identifiers which must be unpredictable.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct XorShiftRng(u64);
+
+impl XorShiftRng {
+    /// Provides a new generator from a seed. A zero seed is replaced by a fixed non-zero one.
+    pub fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 0x9e3779b97f4a7c15 } else { seed })
+    }
+}
+
+impl RngCore for XorShiftRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Implements the rand 0.8 [Fill] trait (the `rand` dependency is pinned to 0.8, as `Fill` changed in 0.9),
+/// so that `rng.fill(&mut euui)` works.
+impl Fill for Euui {
+    /// Fills the 4 u128s of this EUUI with random data drawn from `rng`.
+    fn try_fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), rand::Error> {
+        self.0.try_fill(rng)
+    }
+}
+
+impl Euui {
+    /// Provides a new random 512-bits EUUI.
+    pub fn random() -> Self {
+        Self([random(), random(), random(), random()])
+    }
+
+    /// Provides a new random 512-bits EUUI drawn from the given random number generator.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self([rng.gen(), rng.gen(), rng.gen(), rng.gen()])
+    }
+
+    /// Provides a new EUUI keeping the `Some` words of `fixed`, the `None` words being drawn from `rng`.
+    pub fn random_from_words<R: Rng + ?Sized>(fixed: [Option<u128>; 4], rng: &mut R) -> Self {
+        Self(fixed.map(|word| word.unwrap_or_else(|| rng.gen())))
+    }
+
+    /// Provides a new random 512-bits EUUI drawn from `rng` which is guaranteed not to be [Euui::zero].
+    ///
+    /// An all-zero draw (astronomically rare with a sound RNG) is regenerated.
+    pub fn random_nonzero<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let euui = Self::random_with(rng);
+            if !euui.is_zero() {
+                return euui;
+            }
+        }
+    }
+
+    /// Provides a new random 512-bits EUUI drawn from `rng`, with its last byte (index 63) replaced
+    /// by the [Euui::TAG] marker so that it can be recognized with [Euui::has_tag].
+    ///
+    /// Only 504 bits are random.
+    pub fn random_tagged<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_with(rng).with_byte(63, Self::TAG).unwrap()
+    }
+
+    /// Provides a random EUUI drawn from `rng` for which `predicate` holds, e.g. `|id| id.shard(8) == 3`.
+    ///
+    /// Returns [None] if no such EUUI was drawn in `max_tries` tries.
+    pub fn random_satisfying<F: Fn(&Euui) -> bool, R: Rng + ?Sized>(predicate: F, rng: &mut R, max_tries: usize) -> Option<Self> {
+        (0..max_tries).map(|_| Self::random_with(rng)).find(|euui| predicate(euui))
+    }
+
+    /// Provides a Snowflake-like EUUI : `counter` in the first u64, `node` in the second u64,
+    /// and 384 random bits drawn from `rng` in the rest.
+    ///
+    /// Use [Euui::snowflake_counter] and [Euui::snowflake_node] to read the structured parts back.
+    pub fn new_snowflake<R: Rng + ?Sized>(counter: u64, node: u64, rng: &mut R) -> Self {
+        Self([(counter as u128) << 64 | node as u128, rng.gen(), rng.gen(), rng.gen()])
+    }
+
+    /// Provides a structured EUUI for distributed generation : a 48-bits Unix timestamp in milliseconds,
+    /// a 32-bits `node_id`, a 32-bits `seq` number, and 400 random bits drawn from `rng` in the rest.
+    ///
+    /// Only the 48 low bits of `unix_millis` are kept (enough until the year 10889).
+    /// EUUIs are thus ordered by time, then by node, then by sequence number.
+    /// Use [Euui::time_node_seq_timestamp_millis], [Euui::time_node_seq_node_id] and
+    /// [Euui::time_node_seq_sequence] to read the fields back.
+    pub fn from_time_node_seq<R: Rng + ?Sized>(unix_millis: u64, node_id: u32, seq: u32, rng: &mut R) -> Self {
+        let fields = ((unix_millis & 0xffff_ffff_ffff) as u128) << 80 | (node_id as u128) << 48 | (seq as u128) << 16;
+        Self([fields | rng.gen::<u16>() as u128, rng.gen(), rng.gen(), rng.gen()])
+    }
+
+    /// Provides a new random 512-bits EUUI from 8 raw u64s of `rng`.
+    ///
+    /// Paired with a fast generator like [XorShiftRng], this is suited for simulations but is **not cryptographic**.
+    pub fn random_fast<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut longs = [0u64; 8];
+        for long in longs.iter_mut() {
+            *long = rng.next_u64();
+        }
+        Self::from_be_longs(longs)
+    }
+
+    /// Fills `slice` with random EUUIs drawn from `rng`.
+    ///
+    /// All the random bytes are requested from `rng` in a single call, which is faster than
+    /// generating the EUUIs one by one.
+    pub fn fill_random<R: RngCore + ?Sized>(slice: &mut [Euui], rng: &mut R) {
+        let mut bytes = vec![0u8; slice.len() * 64];
+        rng.fill_bytes(&mut bytes);
+        for (euui, chunk) in slice.iter_mut().zip(bytes.chunks_exact(64)) {
+            *euui = Self::from_be_bytes(chunk.try_into().unwrap());
+        }
+    }
+
+    /// Provides a random EUUI where each of the 512 bits is set with the probability `ones_probability`,
+    /// e.g. to test sparse or dense bitset-like EUUIs.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ones_probability` is not in `0.0..=1.0`.
+    pub fn random_weighted_bits<R: Rng + ?Sized>(ones_probability: f64, rng: &mut R) -> Self {
+        let mut guids = [0u128; 4];
+        for guid in guids.iter_mut() {
+            *guid = (0..128).fold(0, |word, _| word << 1 | rng.gen_bool(ones_probability) as u128);
+        }
+        Self(guids)
+    }
+
+    /// Provides a random EUUI uniformly distributed in `[low, high)`.
+    ///
+    /// Random values are drawn over the bit width of `high - low` and rejected when out of range,
+    /// so less than 2 draws are needed on average.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is empty (`low >= high`).
+    pub fn gen_range<R: Rng + ?Sized>(low: &Euui, high: &Euui, rng: &mut R) -> Self {
+        assert!(low < high, "Cannot sample an empty range");
+        let width = high.wrapping_sub(low);
+        let mask = Self::low_bits_mask(512 - width.common_prefix_len(&Self::zero()));
+        loop {
+            let offset = Self::random_with(rng) & mask;
+            if offset < width {
+                return low.wrapping_add(&offset);
+            }
+        }
+    }
+
+    /// Provides a random EUUI within the subnet of `prefix` : the `prefix_bits` most significant bits
+    /// are taken from `prefix` and the `512 - prefix_bits` other bits are drawn from `rng`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `prefix_bits > 512`.
+    pub fn random_in_subnet<R: Rng + ?Sized>(prefix: &Euui, prefix_bits: u32, rng: &mut R) -> Self {
+        assert!(prefix_bits <= 512, "A prefix cannot be longer than 512 bits");
+        let host_mask = Self::low_bits_mask(512 - prefix_bits);
+        Self::select(&host_mask, &Self::random_with(rng), prefix)
+    }
+
+    /// Provides a random EUUI keeping all the bits of `prefix` but its `random_bits` least significant ones,
+    /// which are drawn from `rng`.
+    ///
+    /// This is the same as [Euui::random_in_subnet] with `512 - random_bits` prefix bits.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `random_bits > 512`.
+    pub fn with_random_suffix_bits<R: Rng + ?Sized>(prefix: &Euui, random_bits: u32, rng: &mut R) -> Self {
+        assert!(random_bits <= 512, "Cannot randomize more than 512 bits");
+        Self::random_in_subnet(prefix, 512 - random_bits, rng)
+    }
+
+    /// Provides a random EUUI drawn from `rng` which is not in `existing`.
+    ///
+    /// Returns a [CollisionLimitExceeded] if all the `max_tries` EUUIs drawn were in `existing`
+    /// (e.g. with a broken or deterministic RNG), instead of looping forever.
+    pub fn random_avoiding<R: Rng + ?Sized>(
+        existing: &HashSet<Euui>,
+        rng: &mut R,
+        max_tries: usize,
+    ) -> Result<Self, CollisionLimitExceeded> {
+        (0..max_tries)
+            .map(|_| Self::random_with(rng))
+            .find(|euui| !existing.contains(euui))
+            .ok_or(CollisionLimitExceeded { attempts: max_tries })
+    }
+
+    /// Provides `n` pairwise-distinct random EUUIs drawn from `rng`.
+    ///
+    /// A colliding EUUI (astronomically rare) is regenerated, each EUUI being drawn with [Euui::random_avoiding].
+    ///
+    /// Returns a [CollisionLimitExceeded] if, for one of the EUUIs, all the `max_tries` EUUIs drawn were already
+    /// in the set (e.g. with a broken or deterministic RNG), instead of looping forever.
+    pub fn distinct_set<R: Rng + ?Sized>(
+        n: usize,
+        rng: &mut R,
+        max_tries: usize,
+    ) -> Result<Vec<Euui>, CollisionLimitExceeded> {
+        let mut seen = HashSet::with_capacity(n);
+        let mut ids = Vec::with_capacity(n);
+        while ids.len() < n {
+            let id = Self::random_avoiding(&seen, rng, max_tries)?;
+            seen.insert(id);
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    /// Returns an endless iterator of random EUUIs drawn from `rng`.
+    pub fn sample_iter<R: Rng>(mut rng: R) -> impl Iterator<Item = Euui> {
+        std::iter::repeat_with(move || Self::random_with(&mut rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CollisionLimitExceeded, Euui, XorShiftRng};
+    use rand::rngs::mock::StepRng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, RngCore, SeedableRng};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_random_with_seeded() {
+        let a = Euui::random_with(&mut StdRng::seed_from_u64(42));
+        let b = Euui::random_with(&mut StdRng::seed_from_u64(42));
+        assert_eq!(a, b);
+        assert_ne!(a, Euui::default());
+    }
+
+    /// Yields zeros for the first EUUI (8 u64s), then a counter.
+    struct ZeroFirstRng(u64);
+
+    impl RngCore for ZeroFirstRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            if self.0 <= 8 { 0 } else { self.0 }
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.iter_mut().for_each(|byte| *byte = self.next_u64() as u8);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_random_from_words() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let euui = Euui::random_from_words([Some(1), None, Some(3), None], &mut rng);
+        assert_eq!(euui.u128(0), Some(1));
+        assert_eq!(euui.u128(2), Some(3));
+        assert_ne!(euui.u128(1), euui.u128(3));
+
+        assert_eq!(Euui::random_from_words([Some(1), Some(2), Some(3), Some(4)], &mut rng), Euui::new(1, 2, 3, 4));
+        assert_eq!(
+            Euui::random_from_words([None; 4], &mut StdRng::seed_from_u64(23)),
+            Euui::random_with(&mut StdRng::seed_from_u64(23))
+        );
+    }
+
+    #[test]
+    fn test_random_nonzero() {
+        assert!(Euui::random_with(&mut ZeroFirstRng(0)).is_zero());
+        let mut rng = ZeroFirstRng(0);
+        let euui = Euui::random_nonzero(&mut rng);
+        assert!(!euui.is_zero());
+        assert_eq!(euui, Euui::random_with(&mut ZeroFirstRng(8)));
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut euui = Euui::zero();
+        StdRng::seed_from_u64(13).fill(&mut euui);
+        assert!(!euui.is_zero());
+        let mut other = Euui::MAX;
+        StdRng::seed_from_u64(13).fill(&mut other);
+        assert_eq!(euui, other);
+    }
+
+    #[test]
+    fn test_random_tagged() {
+        let mut rng = StdRng::seed_from_u64(21);
+        for _ in 0..100 {
+            let euui = Euui::random_tagged(&mut rng);
+            assert!(euui.has_tag());
+            assert_eq!(euui.u8(63), Some(Euui::TAG));
+        }
+        let untagged = (0..1000).filter(|_| !Euui::random_with(&mut rng).has_tag()).count();
+        assert!(untagged > 980, "{} untagged EUUIs", untagged);
+    }
+
+    #[test]
+    fn test_random_satisfying() {
+        let mut rng = StdRng::seed_from_u64(29);
+        let even = Euui::random_satisfying(|id| id.u128(3).unwrap() & 1 == 0, &mut rng, 64);
+        assert!(even.is_some_and(|id| id.u128(3).unwrap() & 1 == 0));
+        let sharded = Euui::random_satisfying(|id| id.shard(8) == 3, &mut rng, 1000).unwrap();
+        assert_eq!(sharded.shard(8), 3);
+        assert_eq!(Euui::random_satisfying(|id| id.is_zero(), &mut rng, 10), None);
+        assert_eq!(Euui::random_satisfying(|_| true, &mut rng, 0), None);
+    }
+
+    #[test]
+    fn test_new_snowflake() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let euui = Euui::new_snowflake(0x0123456789abcdef, 42, &mut rng);
+        assert_eq!(euui.snowflake_counter(), 0x0123456789abcdef);
+        assert_eq!(euui.snowflake_node(), 42);
+        assert_eq!(euui.u64(0), Some(0x0123456789abcdef));
+        assert_eq!(euui.u64(1), Some(42));
+        assert_ne!(euui.u128(1), Some(0));
+    }
+
+    #[test]
+    fn test_from_time_node_seq() {
+        let mut rng = StdRng::seed_from_u64(37);
+        let euui = Euui::from_time_node_seq(1_760_486_400_000, 0xdead_beef, 42, &mut rng);
+        assert_eq!(euui.time_node_seq_timestamp_millis(), 1_760_486_400_000);
+        assert_eq!(euui.time_node_seq_node_id(), 0xdead_beef);
+        assert_eq!(euui.time_node_seq_sequence(), 42);
+
+        let full = Euui::from_time_node_seq(u64::MAX, u32::MAX, u32::MAX, &mut rng);
+        assert_eq!(full.time_node_seq_timestamp_millis(), 0xffff_ffff_ffff);
+        assert_eq!(full.time_node_seq_node_id(), u32::MAX);
+        assert_eq!(full.time_node_seq_sequence(), u32::MAX);
+
+        let zero = Euui::from_time_node_seq(0, 0, 0, &mut rng);
+        assert_eq!(zero.time_node_seq_timestamp_millis(), 0);
+        assert_eq!(zero.time_node_seq_node_id(), 0);
+        assert_eq!(zero.time_node_seq_sequence(), 0);
+        assert_ne!(u64::from(euui.time_node_seq_node_id()), euui.snowflake_node());
+        assert_ne!(zero.u128(1), Some(0));
+
+        let later = Euui::from_time_node_seq(1_760_486_400_001, 0, 0, &mut rng);
+        assert!(euui < later);
+        assert!(euui < Euui::from_time_node_seq(1_760_486_400_000, 0xdead_beef, 43, &mut rng));
+    }
+
+    #[test]
+    fn test_random_fast() {
+        let mut rng = XorShiftRng::new(12345);
+        let samples = 1000;
+        let ones: u32 = (0..samples)
+            .map(|_| Euui::random_fast(&mut rng).to_be_guids().iter().map(|w| w.count_ones()).sum::<u32>())
+            .sum();
+        let ratio = ones as f64 / (samples * 512) as f64;
+        assert!((0.49..0.51).contains(&ratio), "set-bit ratio {}", ratio);
+
+        let a = Euui::random_fast(&mut XorShiftRng::new(0));
+        let b = Euui::random_fast(&mut XorShiftRng::new(0));
+        assert_eq!(a, b);
+        assert_ne!(a, Euui::default());
+    }
+
+    #[test]
+    fn test_fill_random() {
+        let mut a = [Euui::zero(); 100];
+        let mut b = [Euui::zero(); 100];
+        Euui::fill_random(&mut a, &mut StdRng::seed_from_u64(5));
+        Euui::fill_random(&mut b, &mut StdRng::seed_from_u64(5));
+        assert_eq!(a, b);
+        let unique: HashSet<Euui> = a.iter().copied().collect();
+        assert_eq!(unique.len(), 100);
+
+        let mut empty: [Euui; 0] = [];
+        Euui::fill_random(&mut empty, &mut XorShiftRng::new(1));
+    }
+
+    #[test]
+    fn test_random_weighted_bits() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let samples = 1000;
+        let ones: u32 = (0..samples).map(|_| Euui::random_weighted_bits(0.1, &mut rng).count_ones()).sum();
+        let average = ones as f64 / samples as f64;
+        assert!((49.0..53.5).contains(&average), "average popcount {}", average);
+        assert_eq!(Euui::random_weighted_bits(0.0, &mut rng), Euui::zero());
+        assert_eq!(Euui::random_weighted_bits(1.0, &mut rng), Euui::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_weighted_bits_invalid_probability() {
+        Euui::random_weighted_bits(1.5, &mut StdRng::seed_from_u64(17));
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let low = Euui::new(0, 5, 0, 0);
+        let high = Euui::new(0, 5, 1, 17);
+        for _ in 0..1000 {
+            let euui = Euui::gen_range(&low, &high, &mut rng);
+            assert!(low <= euui && euui < high);
+        }
+        let next = low.wrapping_add(&Euui::new(0, 0, 0, 1));
+        assert_eq!(Euui::gen_range(&low, &next, &mut rng), low);
+        let full = Euui::gen_range(&Euui::zero(), &Euui::MAX, &mut rng);
+        assert!(full < Euui::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_empty() {
+        let low = Euui::new(0, 0, 0, 42);
+        Euui::gen_range(&low, &low, &mut StdRng::seed_from_u64(9));
+    }
+
+    #[test]
+    fn test_random_in_subnet() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let prefix = Euui::random_with(&mut rng);
+        for prefix_bits in [0, 1, 64, 130, 300, 511] {
+            let euui = Euui::random_in_subnet(&prefix, prefix_bits, &mut rng);
+            assert!(euui.common_prefix_len(&prefix) >= prefix_bits);
+        }
+        let euui = Euui::random_in_subnet(&prefix, 130, &mut rng);
+        assert_ne!(euui, Euui::random_in_subnet(&prefix, 130, &mut rng));
+        assert_eq!(Euui::random_in_subnet(&prefix, 512, &mut rng), prefix);
+    }
+
+    #[test]
+    fn test_with_random_suffix_bits() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let prefix = Euui::random_with(&mut rng);
+        assert_eq!(Euui::with_random_suffix_bits(&prefix, 0, &mut rng), prefix);
+        for random_bits in [1, 8, 128, 200, 512] {
+            let euui = Euui::with_random_suffix_bits(&prefix, random_bits, &mut rng);
+            assert_eq!(euui.network_address(512 - random_bits), prefix.network_address(512 - random_bits));
+        }
+        let a = Euui::with_random_suffix_bits(&prefix, 64, &mut rng);
+        let b = Euui::with_random_suffix_bits(&prefix, 64, &mut rng);
+        assert_ne!(a, b);
+        assert_eq!(a.to_be_longs()[..7], prefix.to_be_longs()[..7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_random_suffix_bits_too_many() {
+        Euui::with_random_suffix_bits(&Euui::zero(), 513, &mut StdRng::seed_from_u64(31));
+    }
+
+    #[test]
+    fn test_random_avoiding() {
+        let existing: HashSet<Euui> = [Euui::zero()].into_iter().collect();
+        let mut colliding = StepRng::new(0, 0);
+        assert_eq!(
+            Euui::random_avoiding(&existing, &mut colliding, 10),
+            Err(CollisionLimitExceeded { attempts: 10 })
+        );
+        assert_eq!(Euui::random_avoiding(&HashSet::new(), &mut colliding, 10), Ok(Euui::zero()));
+        assert_eq!(
+            Euui::random_avoiding(&HashSet::new(), &mut colliding, 0),
+            Err(CollisionLimitExceeded { attempts: 0 })
+        );
+
+        let mut rng = ZeroFirstRng(0);
+        let euui = Euui::random_avoiding(&existing, &mut rng, 2).unwrap();
+        assert!(!euui.is_zero());
+        assert_eq!(euui, Euui::random_with(&mut ZeroFirstRng(8)));
+    }
+
+    #[test]
+    fn test_distinct_set() {
+        let ids = Euui::distinct_set(1000, &mut StdRng::seed_from_u64(3), 10).unwrap();
+        assert_eq!(ids.len(), 1000);
+        let unique: HashSet<Euui> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), 1000);
+        assert_eq!(Euui::distinct_set(0, &mut StdRng::seed_from_u64(3), 0), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_distinct_set_constant_rng() {
+        let mut constant = StepRng::new(0, 0);
+        assert_eq!(Euui::distinct_set(1, &mut constant, 10), Ok(vec![Euui::zero()]));
+        assert_eq!(Euui::distinct_set(2, &mut constant, 10), Err(CollisionLimitExceeded { attempts: 10 }));
+        assert_eq!(Euui::distinct_set(1000, &mut constant, 3), Err(CollisionLimitExceeded { attempts: 3 }));
+    }
+
+    #[test]
+    fn test_sample_iter() {
+        let a: Vec<Euui> = Euui::sample_iter(StdRng::seed_from_u64(7)).take(5).collect();
+        let b: Vec<Euui> = Euui::sample_iter(StdRng::seed_from_u64(7)).take(5).collect();
+        assert_eq!(a.len(), 5);
+        assert_eq!(a, b);
+        assert_ne!(a[0], a[1]);
+    }
+}
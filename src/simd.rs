@@ -0,0 +1,55 @@
+use crate::Euui;
+use core::simd::u64x8;
+
+impl Euui {
+    /// Returns the 8 big-endian u64s composing this EUUI (as in [Euui::to_be_longs]) as a portable SIMD vector.
+    pub fn to_simd_u64x8(&self) -> u64x8 {
+        let [a, b, c, d] = self.0;
+        u64x8::from_array([
+            (a >> 64) as u64, a as u64,
+            (b >> 64) as u64, b as u64,
+            (c >> 64) as u64, c as u64,
+            (d >> 64) as u64, d as u64,
+        ])
+    }
+
+    /// Provides a 512-bits EUUI from a portable SIMD vector of 8 big-endian u64s (as in [Euui::from_be_longs]).
+    pub fn from_simd_u64x8(lanes: u64x8) -> Self {
+        let l = lanes.to_array();
+        Self::from_u64_pairs([(l[0], l[1]), (l[2], l[3]), (l[4], l[5]), (l[6], l[7])])
+    }
+}
+
+/// Compares two EUUIs with a SIMD comparison of their 8 u64 lanes.
+pub(crate) fn eq(a: &Euui, b: &Euui) -> bool {
+    a.to_simd_u64x8() == b.to_simd_u64x8()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{simd, Euui};
+    use crate::tests::sample;
+    use core::simd::u64x8;
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        for n in 0..100 {
+            let a = sample(2 * n);
+            let b = sample(2 * n + 1);
+            assert_eq!(simd::eq(&a, &b), a.0 == b.0);
+            assert!(simd::eq(&a, &a));
+        }
+    }
+
+    #[test]
+    fn test_simd_u64x8() {
+        let euui = sample(3);
+        let lanes = euui.to_simd_u64x8();
+        assert_eq!(lanes.to_array(), euui.to_be_longs());
+        assert_eq!(Euui::from_simd_u64x8(lanes), euui);
+        assert_eq!(
+            Euui::from_simd_u64x8(u64x8::from_array([0, 1, 2, 3, 4, 5, 6, 7])),
+            Euui::from_be_longs([0, 1, 2, 3, 4, 5, 6, 7])
+        );
+    }
+}
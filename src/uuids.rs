@@ -0,0 +1,66 @@
+use crate::Euui;
+use uuid::Uuid;
+
+impl Euui {
+    /// Provides a 512-bits EUUI from 4 UUIDs.
+    pub fn from_uuids(uuids: [Uuid; 4]) -> Self {
+        Self(uuids.map(|uuid| uuid.as_u128()))
+    }
+
+    /// Returns the 4 u128s composing this EUUI as UUIDs.
+    pub fn to_uuids(&self) -> [Uuid; 4] {
+        self.0.map(Uuid::from_u128)
+    }
+
+    /// Provides a 512-bits EUUI from 4 raw 16-bytes UUIDs (as in [uuid::Bytes]).
+    pub fn from_uuid_bytes(bytes: [[u8; 16]; 4]) -> Self {
+        Self(bytes.map(u128::from_be_bytes))
+    }
+
+    /// Returns the 4 u128s composing this EUUI as raw 16-bytes UUIDs (as in [uuid::Bytes]).
+    pub fn to_uuid_bytes(&self) -> [[u8; 16]; 4] {
+        self.0.map(u128::to_be_bytes)
+    }
+
+    /// Returns this EUUI as a single UUID if it fits in its last u128 (ie. if the words 0 to 2 are zero).
+    ///
+    /// Returns [None] otherwise.
+    pub fn as_single_uuid(&self) -> Option<Uuid> {
+        if self.0[..3].iter().all(|&word| word == 0) { Some(Uuid::from_u128(self.0[3])) } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Euui;
+    use crate::tests::sample;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_uuids() {
+        let euui = sample(1);
+        assert_eq!(Euui::from_uuids(euui.to_uuids()), euui);
+        assert_eq!(euui.to_uuids()[2].as_u128(), euui.u128(2).unwrap());
+    }
+
+    #[test]
+    fn test_uuid_bytes() {
+        let euui = sample(2);
+        let bytes = euui.to_uuid_bytes();
+        assert_eq!(Euui::from_uuid_bytes(bytes), euui);
+        for (bytes, uuid) in bytes.iter().zip(euui.to_uuids()) {
+            assert_eq!(bytes, uuid.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_as_single_uuid() {
+        let euui = sample(3);
+        let low = Euui::new(0, 0, 0, euui.u128(3).unwrap());
+        assert_eq!(low.as_single_uuid(), Some(euui.to_uuids()[3]));
+        assert_eq!(Euui::zero().as_single_uuid(), Some(Uuid::nil()));
+        assert_eq!(Euui::new(1, 0, 0, 0).as_single_uuid(), None);
+        assert_eq!(Euui::new(0, 0, 1, 0).as_single_uuid(), None);
+        assert_eq!(Euui::MAX.as_single_uuid(), None);
+    }
+}